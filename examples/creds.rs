@@ -13,7 +13,7 @@ fn main() -> anyhow::Result<()> {
 
     spotify.request_token()?;
 
-    let artist: Artist = GetArtist::from("0559tR6WyukLWH68JIGBuC").query(&spotify)?;
+    let artist: Artist = GetArtist::try_from("0559tR6WyukLWH68JIGBuC")?.query(&spotify)?;
 
     println!("\n{} -> {}\n", artist.name, artist.external_urls.spotify);
 
@@ -14,7 +14,7 @@ async fn main() -> anyhow::Result<()> {
 
     spotify.request_token().await?;
 
-    let artist: Artist = GetArtist::from("0559tR6WyukLWH68JIGBuC")
+    let artist: Artist = GetArtist::try_from("0559tR6WyukLWH68JIGBuC")?
         .query_async(&spotify)
         .await?;
 
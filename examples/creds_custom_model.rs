@@ -23,7 +23,7 @@ fn main() -> anyhow::Result<()> {
 
     spotify.request_token()?;
 
-    let track: Track = GetTrack::from("2HZasNmIAzprwJjFLPoSGM").query(&spotify)?;
+    let track: Track = GetTrack::try_from("2HZasNmIAzprwJjFLPoSGM")?.query(&spotify)?;
 
     let artist_names = track
         .artists
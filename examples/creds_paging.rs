@@ -14,7 +14,7 @@ fn main() -> anyhow::Result<()> {
     spotify.request_token()?;
 
     let tracks: Vec<SimplifiedTrack> =
-        api::paged_all(GetAlbumTracks::from("7F50uh7oGitmAEScRKV6pD")).query(&spotify)?;
+        api::paged_all(GetAlbumTracks::try_from("7F50uh7oGitmAEScRKV6pD")?).query(&spotify)?;
 
     for track in tracks {
         println!("{}", track.name);
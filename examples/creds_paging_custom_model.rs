@@ -18,7 +18,8 @@ fn main() -> anyhow::Result<()> {
     spotify.request_token()?;
 
     let tracks: Vec<Track> =
-        api::paged_with_limit(GetAlbumTracks::from("7F50uh7oGitmAEScRKV6pD"), 6).query(&spotify)?;
+        api::paged_with_limit(GetAlbumTracks::try_from("7F50uh7oGitmAEScRKV6pD")?, 6)
+            .query(&spotify)?;
 
     for track in tracks {
         println!("{}", track.name);
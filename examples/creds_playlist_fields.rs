@@ -0,0 +1,58 @@
+use spotify_web_api::{
+    Spotify,
+    api::{self, Query as _, playlists::GetPlaylistItems},
+    model::Fields,
+};
+use std::env;
+
+#[derive(serde::Deserialize)]
+struct PlaylistItem {
+    track: Option<Track>,
+}
+
+#[derive(serde::Deserialize)]
+struct Track {
+    name: String,
+    artists: Vec<Artist>,
+}
+
+#[derive(serde::Deserialize)]
+struct Artist {
+    name: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let client_id = env::var("SPOTIFY_CLIENT_ID")?;
+    let client_secret = env::var("SPOTIFY_CLIENT_SECRET")?;
+
+    let spotify = Spotify::with_client_credentials(client_id, client_secret)?;
+
+    spotify.request_token()?;
+
+    // Only request the fields `PlaylistItem` needs, keeping the page details for pagination.
+    let fields = Fields::page(
+        Fields::new().nested(
+            "track",
+            Fields::new()
+                .field("name")
+                .nested("artists", Fields::new().field("name")),
+        ),
+    );
+
+    let items: Vec<PlaylistItem> =
+        api::paged_all(GetPlaylistItems::try_from("3cEYpjA9oz9GiPac4AsH4n")?.fields(fields))
+            .query(&spotify)?;
+
+    for track in items.into_iter().filter_map(|item| item.track) {
+        let artist_names = track
+            .artists
+            .iter()
+            .map(|artist| artist.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+
+        println!("{} - {}", track.name, artist_names);
+    }
+
+    Ok(())
+}
@@ -31,7 +31,7 @@ fn main() -> anyhow::Result<()> {
 
     spotify.request_token_from_redirect_url(redirect_url)?;
 
-    let artist: Artist = GetArtist::from("0559tR6WyukLWH68JIGBuC").query(&spotify)?;
+    let artist: Artist = GetArtist::try_from("0559tR6WyukLWH68JIGBuC")?.query(&spotify)?;
 
     println!("\n{} -> {}\n", artist.name, artist.external_urls.spotify);
 
@@ -34,7 +34,7 @@ async fn main() -> anyhow::Result<()> {
         .request_token_from_redirect_url(redirect_url)
         .await?;
 
-    let artist: Artist = GetArtist::from("0559tR6WyukLWH68JIGBuC")
+    let artist: Artist = GetArtist::try_from("0559tR6WyukLWH68JIGBuC")?
         .query_async(&spotify)
         .await?;
 
@@ -23,6 +23,7 @@ pub mod player;
 pub mod playlists;
 pub mod search;
 pub mod shows;
+pub mod token;
 pub mod tracks;
 pub mod users;
 
@@ -38,9 +39,13 @@ pub use raw::*;
 mod prelude {
     pub use super::Pageable;
     pub use crate::{
-        api::{BodyError, Endpoint, JsonParams, QueryParams},
+        api::{BodyError, Endpoint, FormParams, JsonParams, QueryParams, UrlBase},
+        auth::{
+            Secret,
+            scopes::{self, Scope},
+        },
         model::Market,
     };
     pub use http::Method;
-    pub use std::borrow::Cow;
+    pub use std::{borrow::Cow, collections::HashSet};
 }
@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{AlbumId, IdError, parse_ids},
+};
 
 /// Check if one or more albums is already saved in the current Spotify user's 'Your Music' library.
 #[derive(Debug, Clone)]
 pub struct CheckUserSavedAlbums {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the albums.
-    pub ids: Vec<String>,
+    pub ids: Vec<AlbumId>,
 }
 
-impl<T, I> From<I> for CheckUserSavedAlbums
+impl<I> From<I> for CheckUserSavedAlbums
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = AlbumId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl CheckUserSavedAlbums {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<AlbumId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for CheckUserSavedAlbums {
     fn method(&self) -> Method {
         Method::GET
@@ -30,9 +39,13 @@ impl Endpoint for CheckUserSavedAlbums {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -57,11 +70,12 @@ mod tests {
 
         let client = SingleTestClient::new_json(endpoint, &expected_response);
 
-        let endpoint = CheckUserSavedAlbums::from([
+        let endpoint = CheckUserSavedAlbums::from_ids([
             "382ObEPsp2rxGrnsizN5TX",
             "1A2GTWGtFfWp7KSQTwWOyo",
             "2noRn2Aes5aoNVsU6iWThc",
-        ]);
+        ])
+        .unwrap();
 
         let result: Vec<bool> = endpoint.query(&client).unwrap();
 
@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{AlbumId, IdError},
+};
 
 /// Get Spotify catalog information for a single album.
 #[derive(Debug, Clone)]
 pub struct GetAlbum {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the album.
-    pub id: String,
+    pub id: AlbumId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,12 +19,25 @@ pub struct GetAlbum {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetAlbum {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<AlbumId> for GetAlbum {
+    fn from(id: AlbumId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetAlbum {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        AlbumId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetAlbum {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        AlbumId::from_id(id).map(Self::from)
     }
 }
 
@@ -31,7 +47,7 @@ impl Endpoint for GetAlbum {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("albums/{}", self.id).into()
+        format!("albums/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -57,7 +73,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetAlbum::from("0oKvU088cLhKbbVvQc9lQF");
+        let endpoint = GetAlbum::try_from("0oKvU088cLhKbbVvQc9lQF").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{AlbumId, IdError},
+};
 
 /// Get Spotify catalog information about an album’s tracks. Optional parameters can be used to limit the number of tracks returned.
 #[derive(Debug, Clone)]
 pub struct GetAlbumTracks {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the album.
-    pub id: String,
+    pub id: AlbumId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -18,12 +21,25 @@ pub struct GetAlbumTracks {
 
 impl Pageable for GetAlbumTracks {}
 
-impl<T: Into<String>> From<T> for GetAlbumTracks {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<AlbumId> for GetAlbumTracks {
+    fn from(id: AlbumId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetAlbumTracks {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        AlbumId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetAlbumTracks {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        AlbumId::from_id(id).map(Self::from)
     }
 }
 
@@ -33,7 +49,7 @@ impl Endpoint for GetAlbumTracks {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("albums/{}/tracks", self.id).into()
+        format!("albums/{}/tracks", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -59,7 +75,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetAlbumTracks::from("66q0q162WOFuidbucN1Jdp");
+        let endpoint = GetAlbumTracks::try_from("66q0q162WOFuidbucN1Jdp").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,9 +1,12 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{AlbumId, IdError, parse_ids},
+};
 
 /// Get Spotify catalog information for multiple albums identified by their Spotify IDs.
 pub struct GetSeveralAlbums {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the albums.
-    pub ids: Vec<String>,
+    pub ids: Vec<AlbumId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -15,19 +18,25 @@ pub struct GetSeveralAlbums {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for GetSeveralAlbums
+impl<I> From<I> for GetSeveralAlbums
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = AlbumId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl GetSeveralAlbums {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<AlbumId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralAlbums {
     fn method(&self) -> Method {
         Method::GET
@@ -39,7 +48,7 @@ impl Endpoint for GetSeveralAlbums {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
@@ -65,11 +74,12 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetSeveralAlbums::from([
+        let endpoint = GetSeveralAlbums::from_ids([
             "382ObEPsp2rxGrnsizN5TX",
             "1A2GTWGtFfWp7KSQTwWOyo",
             "2noRn2Aes5aoNVsU6iWThc",
-        ]);
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -40,6 +40,10 @@ impl Endpoint for GetUserSavedAlbums {
         params.push_opt("market", self.market.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{AlbumId, IdError, parse_ids},
+};
 
 /// Save one or more albums to the current user's 'Your Music' library.
 #[derive(Debug, Clone)]
 pub struct SaveAlbumsforCurrentUser {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the albums.
-    pub ids: Vec<String>,
+    pub ids: Vec<AlbumId>,
 }
 
-impl<T, I> From<I> for SaveAlbumsforCurrentUser
+impl<I> From<I> for SaveAlbumsforCurrentUser
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = AlbumId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl SaveAlbumsforCurrentUser {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<AlbumId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for SaveAlbumsforCurrentUser {
     fn method(&self) -> Method {
         Method::PUT
@@ -30,9 +39,13 @@ impl Endpoint for SaveAlbumsforCurrentUser {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -54,8 +67,11 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint =
-            SaveAlbumsforCurrentUser::from(["7F50uh7oGitmAEScRKV6pD", "27XW2QTeqZGOKlm2Dt0PvN"]);
+        let endpoint = SaveAlbumsforCurrentUser::from_ids([
+            "7F50uh7oGitmAEScRKV6pD",
+            "27XW2QTeqZGOKlm2Dt0PvN",
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
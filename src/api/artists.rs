@@ -1,9 +1,11 @@
 mod get_artist;
 mod get_artist_albums;
+mod get_artist_related_artists;
 mod get_artist_top_tracks;
 mod get_several_artists;
 
 pub use get_artist::*;
 pub use get_artist_albums::*;
+pub use get_artist_related_artists::*;
 pub use get_artist_top_tracks::*;
 pub use get_several_artists::*;
@@ -1,15 +1,34 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{ArtistId, IdError},
+};
 
 /// Get Spotify catalog information for a single artist identified by their unique Spotify ID.
 #[derive(Debug, Clone)]
 pub struct GetArtist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the artist.
-    pub id: String,
+    pub id: ArtistId,
 }
 
-impl<T: Into<String>> From<T> for GetArtist {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<ArtistId> for GetArtist {
+    fn from(id: ArtistId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetArtist {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetArtist {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
     }
 }
 
@@ -19,7 +38,7 @@ impl Endpoint for GetArtist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("artists/{}", self.id).into()
+        format!("artists/{}", self.id.id()).into()
     }
 }
 
@@ -39,7 +58,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint: GetArtist = GetArtist::from("5dRk8JyA2Tg9wL0iiTqbVu");
+        let endpoint: GetArtist = GetArtist::try_from("5dRk8JyA2Tg9wL0iiTqbVu").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,13 +1,13 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::AlbumType,
+    model::{AlbumType, ArtistId, IdError},
 };
 
 /// Get Spotify catalog information about an artist's albums.
 #[derive(Debug, Clone)]
 pub struct GetArtistAlbums {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the artist.
-    pub id: String,
+    pub id: ArtistId,
 
     /// A list of keywords that will be used to filter the response. If not supplied, all album types will be returned.
     pub include_groups: Option<Vec<AlbumType>>,
@@ -23,7 +23,7 @@ pub struct GetArtistAlbums {
 }
 
 impl GetArtistAlbums {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: ArtistId) -> Self {
         Self::from(id)
     }
 
@@ -47,7 +47,7 @@ impl Endpoint for GetArtistAlbums {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("artists/{}/albums", self.id).into()
+        format!("artists/{}/albums", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -68,16 +68,32 @@ impl Endpoint for GetArtistAlbums {
 
 impl Pageable for GetArtistAlbums {}
 
-impl<T: Into<String>> From<T> for GetArtistAlbums {
-    fn from(id: T) -> Self {
+impl From<ArtistId> for GetArtistAlbums {
+    fn from(id: ArtistId) -> Self {
         Self {
-            id: id.into(),
+            id,
             market: None,
             include_groups: None,
         }
     }
 }
 
+impl TryFrom<&str> for GetArtistAlbums {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetArtistAlbums {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +111,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetArtistAlbums::new("0TnOYISbd1XYRBk9myaseg")
+        let endpoint = GetArtistAlbums::new(ArtistId::from_id("0TnOYISbd1XYRBk9myaseg").unwrap())
             .include_group(AlbumType::Single)
             .include_group(AlbumType::AppearsOn);
 
@@ -110,7 +126,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetArtistAlbums::new("0TnOYISbd1XYRBk9myaseg");
+        let endpoint = GetArtistAlbums::new(ArtistId::from_id("0TnOYISbd1XYRBk9myaseg").unwrap());
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -0,0 +1,100 @@
+use crate::{
+    api::prelude::*,
+    model::{ArtistId, IdError},
+};
+
+/// Get Spotify catalog information about artists similar to a given artist.
+/// Similarity is based on analysis of the Spotify community's listening history.
+#[derive(Debug, Clone)]
+pub struct GetArtistRelatedArtists {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the artist.
+    pub id: ArtistId,
+}
+
+impl From<ArtistId> for GetArtistRelatedArtists {
+    fn from(id: ArtistId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetArtistRelatedArtists {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetArtistRelatedArtists {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
+impl Endpoint for GetArtistRelatedArtists {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("artists/{}/related-artists", self.id.id()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::Query as _,
+        model::Artists,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_artist_related_artists_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("artists/0TnOYISbd1XYRBk9myaseg/related-artists")
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"
+            {
+                "artists": [
+                    {
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/artist/6sFIWsNpZYqfjUpaCgueju"
+                        },
+                        "followers": {
+                            "href": null,
+                            "total": 1234567
+                        },
+                        "genres": ["dance pop", "pop"],
+                        "href": "https://api.spotify.com/v1/artists/6sFIWsNpZYqfjUpaCgueju",
+                        "id": "6sFIWsNpZYqfjUpaCgueju",
+                        "images": [],
+                        "name": "Carly Rae Jepsen",
+                        "popularity": 70,
+                        "type": "artist",
+                        "uri": "spotify:artist:6sFIWsNpZYqfjUpaCgueju"
+                    }
+                ]
+            }
+            "#,
+        );
+
+        let endpoint = GetArtistRelatedArtists::try_from("0TnOYISbd1XYRBk9myaseg").unwrap();
+
+        let artists: Artists = endpoint.query(&client).unwrap();
+
+        assert_eq!(artists.artists.len(), 1);
+        assert_eq!(
+            artists.artists[0]
+                .as_ref()
+                .map(|artist| artist.name.as_str()),
+            Some("Carly Rae Jepsen")
+        );
+    }
+}
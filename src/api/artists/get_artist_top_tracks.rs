@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{ArtistId, IdError},
+};
 
 /// Get Spotify catalog information about an artist's top tracks by country.
 #[derive(Debug, Clone)]
 pub struct GetArtistTopTracks {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the artist.
-    pub id: String,
+    pub id: ArtistId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,12 +19,25 @@ pub struct GetArtistTopTracks {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetArtistTopTracks {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<ArtistId> for GetArtistTopTracks {
+    fn from(id: ArtistId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetArtistTopTracks {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetArtistTopTracks {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ArtistId::from_id(id).map(Self::from)
     }
 }
 
@@ -31,7 +47,7 @@ impl Endpoint for GetArtistTopTracks {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("artists/{}/top-tracks", self.id).into()
+        format!("artists/{}/top-tracks", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -57,7 +73,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetArtistTopTracks::from("0TnOYISbd1XYRBk9myaseg");
+        let endpoint = GetArtistTopTracks::try_from("0TnOYISbd1XYRBk9myaseg").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
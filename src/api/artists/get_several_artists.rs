@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{ArtistId, IdError, parse_ids},
+};
 
 /// Get Spotify catalog information for several artists based on their Spotify IDs.
 #[derive(Debug, Clone)]
 pub struct GetSeveralArtists {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the artists.
-    pub ids: Vec<String>,
+    pub ids: Vec<ArtistId>,
 }
 
-impl<T, I> From<I> for GetSeveralArtists
+impl<I> From<I> for GetSeveralArtists
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = ArtistId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl GetSeveralArtists {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<ArtistId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralArtists {
     fn method(&self) -> Method {
         Method::GET
@@ -30,7 +39,7 @@ impl Endpoint for GetSeveralArtists {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
 }
@@ -55,11 +64,12 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetSeveralArtists::from([
+        let endpoint = GetSeveralArtists::from_ids([
             "2CIMQHirSU0MQqyYHq0eOx",
             "57dN52uHvrHOxijzpIgu3E",
             "1vCWHaC5f2uS3yhpwWbIA6",
-        ]);
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
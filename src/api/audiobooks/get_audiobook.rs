@@ -1,11 +1,14 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{AudiobookId, IdError},
+};
 
 /// Get Spotify catalog information for a single audiobook.
 /// Audiobooks are only available within the US, UK, Canada, Ireland, New Zealand and Australia markets.
 #[derive(Debug, Clone)]
 pub struct GetAudiobook {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the audiobook.
-    pub id: String,
+    pub id: AudiobookId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -17,12 +20,25 @@ pub struct GetAudiobook {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetAudiobook {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<AudiobookId> for GetAudiobook {
+    fn from(id: AudiobookId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetAudiobook {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        AudiobookId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetAudiobook {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        AudiobookId::from_id(id).map(Self::from)
     }
 }
 
@@ -32,7 +48,7 @@ impl Endpoint for GetAudiobook {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("audiobooks/{}", self.id).into()
+        format!("audiobooks/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -58,7 +74,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetAudiobook::from("7iHfbu1YPACw6oZPAFJtqe");
+        let endpoint = GetAudiobook::try_from("7iHfbu1YPACw6oZPAFJtqe").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -14,6 +14,10 @@ impl Endpoint for GetUserSavedAudiobooks {
     fn endpoint(&self) -> Cow<'static, str> {
         "me/audiobooks".into()
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
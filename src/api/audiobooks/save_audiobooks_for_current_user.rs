@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{AudiobookId, IdError, parse_ids},
+};
 
 /// Save one or more audiobooks to the current Spotify user's library.
 #[derive(Debug, Clone)]
 pub struct SaveAudiobooksforCurrentUser {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the audiobooks.
-    pub ids: Vec<String>,
+    pub ids: Vec<AudiobookId>,
 }
 
-impl<T, I> From<I> for SaveAudiobooksforCurrentUser
+impl<I> From<I> for SaveAudiobooksforCurrentUser
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = AudiobookId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl SaveAudiobooksforCurrentUser {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<AudiobookId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for SaveAudiobooksforCurrentUser {
     fn method(&self) -> Method {
         Method::PUT
@@ -30,9 +39,13 @@ impl Endpoint for SaveAudiobooksforCurrentUser {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -57,11 +70,12 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = SaveAudiobooksforCurrentUser::from([
+        let endpoint = SaveAudiobooksforCurrentUser::from_ids([
             "18yVqkdbdRvS24c0Ilj2ci",
             "1HGw3J3NxZO1TP1BTtVhpZ",
             "7iHfbu1YPACw6oZPAFJtqe",
-        ]);
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
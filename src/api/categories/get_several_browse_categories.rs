@@ -20,7 +20,11 @@ impl GetSeveralBrowseCategories {
     }
 }
 
-impl Pageable for GetSeveralBrowseCategories {}
+impl Pageable for GetSeveralBrowseCategories {
+    fn page_key(&self) -> Option<&'static str> {
+        Some("categories")
+    }
+}
 
 impl Endpoint for GetSeveralBrowseCategories {
     fn method(&self) -> Method {
@@ -43,9 +47,43 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
+        model::Category,
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
+    const RESPONSE: &str = r#"
+    {
+        "categories": {
+            "href": "https://api.spotify.com/v1/browse/categories?offset=0&limit=20",
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 2,
+            "items": [
+                {
+                    "href": "https://api.spotify.com/v1/browse/categories/toplists",
+                    "icons": [
+                        {
+                            "url": "https://t.scdn.co/media/derived/toplists_11160599e6a04ac5d6f2757f5511778f_0_0_275_275.jpg",
+                            "height": 275,
+                            "width": 275
+                        }
+                    ],
+                    "id": "toplists",
+                    "name": "Top Lists"
+                },
+                {
+                    "href": "https://api.spotify.com/v1/browse/categories/0JQ5DAqbMKFQ00XGBls6ym",
+                    "icons": [],
+                    "id": "0JQ5DAqbMKFQ00XGBls6ym",
+                    "name": "Hip-Hop"
+                }
+            ]
+        }
+    }
+    "#;
+
     #[test]
     fn test_get_several_browse_categories_endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("browse/categories").build();
@@ -70,4 +108,22 @@ mod tests {
             .query(&client)
             .unwrap();
     }
+
+    #[test]
+    fn test_get_several_browse_categories_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/categories")
+            .paginated(true)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, RESPONSE);
+
+        let categories: Vec<Category> = api::paged_all(GetSeveralBrowseCategories::default())
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].id, "toplists");
+        assert_eq!(categories[1].name, "Hip-Hop");
+    }
 }
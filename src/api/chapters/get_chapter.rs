@@ -1,11 +1,14 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{ChapterId, IdError},
+};
 
 /// Get Spotify catalog information for a single audiobook chapter.
 /// Chapters are only available within the US, UK, Canada, Ireland, New Zealand and Australia markets.
 #[derive(Debug, Clone)]
 pub struct GetChapter {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the chapter.
-    pub id: String,
+    pub id: ChapterId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -17,12 +20,25 @@ pub struct GetChapter {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetChapter {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<ChapterId> for GetChapter {
+    fn from(id: ChapterId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetChapter {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ChapterId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetChapter {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ChapterId::from_id(id).map(Self::from)
     }
 }
 
@@ -32,7 +48,7 @@ impl Endpoint for GetChapter {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("chapters/{}", self.id).into()
+        format!("chapters/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -58,7 +74,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetChapter::from("0D5wENdkdwbqlrHoaJ9g29");
+        let endpoint = GetChapter::try_from("0D5wENdkdwbqlrHoaJ9g29").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,11 +1,14 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{ChapterId, IdError, parse_ids},
+};
 
 /// Get Spotify catalog information for several audiobook chapters identified by their Spotify IDs.
 /// Chapters are only available within the US, UK, Canada, Ireland, New Zealand and Australia markets.
 #[derive(Debug, Clone)]
 pub struct GetSeveralChapters {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the chapters.
-    pub ids: Vec<String>,
+    pub ids: Vec<ChapterId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -17,19 +20,25 @@ pub struct GetSeveralChapters {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for GetSeveralChapters
+impl<I> From<I> for GetSeveralChapters
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = ChapterId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl GetSeveralChapters {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<ChapterId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralChapters {
     fn method(&self) -> Method {
         Method::GET
@@ -41,7 +50,7 @@ impl Endpoint for GetSeveralChapters {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
@@ -67,11 +76,12 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetSeveralChapters::from([
+        let endpoint = GetSeveralChapters::from_ids([
             "0IsXVP0JmcB2adSE338GkK",
             "3ZXb8FKZGU0EHALYX6uCzU",
             "0D5wENdkdwbqlrHoaJ9g29",
-        ]);
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,4 +1,5 @@
 use super::error::ApiError;
+use crate::{auth::scopes::RequiredScopes, spotify::BASE_ACCOUNTS_URL};
 use async_trait::async_trait;
 use bytes::Bytes;
 use http::{Response, request::Builder as RequestBuilder};
@@ -14,6 +15,21 @@ pub trait RestClient {
     ///
     /// This method adds the hostname for the client's target instance.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
+
+    /// Get the URL for a Spotify Accounts service endpoint, such as `api/token`.
+    ///
+    /// The default implementation resolves the endpoint against `https://accounts.spotify.com/`.
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(BASE_ACCOUNTS_URL)?.join(endpoint)?)
+    }
+
+    /// Check that the client is authorized for the given scopes before a request is sent.
+    ///
+    /// The default implementation performs no check.
+    fn check_scopes(&self, required: &RequiredScopes) -> Result<(), ApiError<Self::Error>> {
+        let _ = required;
+        Ok(())
+    }
 }
 
 /// A trait representing a client which can communicate with a Spotify instance.
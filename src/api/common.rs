@@ -13,6 +13,28 @@ const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'%')
     .add(b'/');
 
+/// Join a list of values into a single comma-separated parameter value.
+pub fn comma_separated<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Append a value to an optional list, unless the list already contains it.
+pub fn push_unique<T: PartialEq>(values: &mut Option<Vec<T>>, value: T) {
+    let values = values.get_or_insert_with(Vec::new);
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+/// Join a list of typed IDs into a single comma-separated parameter value.
+pub fn comma_separated_ids<T: AsRef<str>>(ids: &[T]) -> String {
+    ids.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(",")
+}
+
 /// Escape a string for usage as a single URL path component.
 pub fn path_escaped(input: &'_ str) -> impl std::fmt::Display + '_ {
     utf8_percent_encode(input, PATH_SEGMENT_ENCODE_SET)
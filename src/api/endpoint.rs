@@ -4,13 +4,14 @@ use super::{
     params::QueryParams,
     query::{self, AsyncQuery, Query},
 };
+use crate::auth::scopes::{RequiredScopes, Scope};
 use async_trait::async_trait;
 use http::{
-    Method, Request,
+    HeaderMap, Method, Request,
     header::{self, LOCATION},
 };
 use serde::de::DeserializeOwned;
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet};
 use url::Url;
 
 /// URL bases for endpoints.
@@ -19,6 +20,9 @@ use url::Url;
 pub enum UrlBase {
     /// An endpoint uses the API v1 URL prefix.
     ApiV1,
+
+    /// An endpoint uses the Spotify Accounts service URL prefix.
+    Accounts,
 }
 
 impl UrlBase {
@@ -29,6 +33,7 @@ impl UrlBase {
     {
         match self {
             Self::ApiV1 => client.rest_endpoint(endpoint),
+            Self::Accounts => client.accounts_endpoint(endpoint),
         }
     }
 }
@@ -57,6 +62,28 @@ pub trait Endpoint {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         Ok(None)
     }
+
+    /// Additional headers for the endpoint, such as the `Authorization` header of a token request.
+    fn headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+
+    /// The [scopes](https://developer.spotify.com/documentation/web-api/concepts/scopes) the
+    /// access token must have been granted for the endpoint to succeed.
+    ///
+    /// Clients may use this to fail fast instead of sending a request that Spotify would reject.
+    fn required_scopes(&self) -> HashSet<Scope> {
+        HashSet::new()
+    }
+
+    /// Scopes of which the access token must have been granted at least one, in addition to
+    /// the [`required_scopes`](Self::required_scopes).
+    ///
+    /// For example, modifying a playlist requires `playlist-modify-public` for a public
+    /// playlist and `playlist-modify-private` for a private one, so either is accepted.
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        HashSet::new()
+    }
 }
 
 impl<E> Endpoint for &E
@@ -82,6 +109,18 @@ where
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         (*self).body()
     }
+
+    fn headers(&self) -> HeaderMap {
+        (*self).headers()
+    }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        (*self).required_scopes()
+    }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        (*self).any_of_scopes()
+    }
 }
 
 impl<E, T, C> Query<T, C> for E
@@ -91,6 +130,8 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(self))?;
+
         let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
 
         self.parameters().add_to_url(&mut url);
@@ -103,6 +144,10 @@ where
             .method(self.method())
             .uri(query::url_to_http_uri(&url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -118,7 +163,7 @@ where
             .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
 
         if !status.is_success() {
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
@@ -135,6 +180,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(self))?;
+
         let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
 
         self.parameters().add_to_url(&mut url);
@@ -147,6 +194,10 @@ where
             .method(self.method())
             .uri(query::url_to_http_uri(&url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -162,7 +213,7 @@ where
             .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
 
         if !status.is_success() {
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
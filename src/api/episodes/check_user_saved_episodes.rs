@@ -1,4 +1,7 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{EpisodeId, IdError, parse_ids},
+};
 
 /// Check if one or more episodes is already saved in the current Spotify user's 'Your Episodes' library.
 ///
@@ -6,21 +9,27 @@ use crate::api::prelude::*;
 #[derive(Debug, Clone)]
 pub struct CheckUserSavedEpisodes {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the episodes.
-    pub ids: Vec<String>,
+    pub ids: Vec<EpisodeId>,
 }
 
-impl<T, I> From<I> for CheckUserSavedEpisodes
+impl<I> From<I> for CheckUserSavedEpisodes
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = EpisodeId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl CheckUserSavedEpisodes {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<EpisodeId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for CheckUserSavedEpisodes {
     fn method(&self) -> Method {
         Method::GET
@@ -32,9 +41,13 @@ impl Endpoint for CheckUserSavedEpisodes {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +70,8 @@ mod tests {
         let client = SingleTestClient::new_json(endpoint, &expected_response);
 
         let endpoint =
-            CheckUserSavedEpisodes::from(["77o6BIVlYM3msb4MMIL1jH", "0Q86acNRm6V9GYx55SXKwf"]);
+            CheckUserSavedEpisodes::from_ids(["77o6BIVlYM3msb4MMIL1jH", "0Q86acNRm6V9GYx55SXKwf"])
+                .unwrap();
 
         let result: Vec<bool> = endpoint.query(&client).unwrap();
 
@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{EpisodeId, IdError},
+};
 
 /// Get Spotify catalog information for a single episode identified by its unique Spotify ID.
 #[derive(Debug, Clone)]
 pub struct GetEpisode {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the episode.
-    pub id: String,
+    pub id: EpisodeId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,12 +19,25 @@ pub struct GetEpisode {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetEpisode {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<EpisodeId> for GetEpisode {
+    fn from(id: EpisodeId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetEpisode {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        EpisodeId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetEpisode {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        EpisodeId::from_id(id).map(Self::from)
     }
 }
 
@@ -31,7 +47,7 @@ impl Endpoint for GetEpisode {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("episodes/{}", self.id).into()
+        format!("episodes/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -57,7 +73,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetEpisode::from("512ojhOuo1ktJprKbVcKyQ");
+        let endpoint = GetEpisode::try_from("512ojhOuo1ktJprKbVcKyQ").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{EpisodeId, IdError, parse_ids},
+};
 
 /// Get Spotify catalog information for several episodes based on their Spotify IDs.
 #[derive(Debug, Clone)]
 pub struct GetSeveralEpisodes {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the episodes.
-    pub ids: Vec<String>,
+    pub ids: Vec<EpisodeId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,19 +19,25 @@ pub struct GetSeveralEpisodes {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for GetSeveralEpisodes
+impl<I> From<I> for GetSeveralEpisodes
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = EpisodeId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl GetSeveralEpisodes {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<EpisodeId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralEpisodes {
     fn method(&self) -> Method {
         Method::GET
@@ -40,7 +49,7 @@ impl Endpoint for GetSeveralEpisodes {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
@@ -64,7 +73,8 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint =
-            GetSeveralEpisodes::from(["77o6BIVlYM3msb4MMIL1jH", "0Q86acNRm6V9GYx55SXKwf"]);
+            GetSeveralEpisodes::from_ids(["77o6BIVlYM3msb4MMIL1jH", "0Q86acNRm6V9GYx55SXKwf"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
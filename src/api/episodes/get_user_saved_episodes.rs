@@ -39,6 +39,10 @@ impl Endpoint for GetUserSavedEpisodes {
         params.push_opt("market", self.market.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{EpisodeId, IdError, parse_ids},
+};
 
 /// Remove one or more episodes from the current user's library.
 ///
@@ -6,21 +9,27 @@ use crate::api::prelude::*;
 #[derive(Debug, Clone)]
 pub struct RemoveUserSavedEpisodes {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the episodes.
-    pub ids: Vec<String>,
+    pub ids: Vec<EpisodeId>,
 }
 
-impl<T, I> From<I> for RemoveUserSavedEpisodes
+impl<I> From<I> for RemoveUserSavedEpisodes
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = EpisodeId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl RemoveUserSavedEpisodes {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<EpisodeId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for RemoveUserSavedEpisodes {
     fn method(&self) -> Method {
         Method::DELETE
@@ -32,9 +41,13 @@ impl Endpoint for RemoveUserSavedEpisodes {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -59,11 +72,12 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = RemoveUserSavedEpisodes::from([
+        let endpoint = RemoveUserSavedEpisodes::from_ids([
             "7ouMYWpwJ422jRcDASZB7P",
             "4VqPOruhp5EdPBeR92t6lQ",
             "2takcwOaAZWiXQijPHIx7B",
-        ]);
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,4 +1,7 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{EpisodeId, IdError, parse_ids},
+};
 
 /// Save one or more episodes to the current user's library.
 ///
@@ -6,21 +9,27 @@ use crate::api::prelude::*;
 #[derive(Debug, Clone)]
 pub struct SaveEpisodesforCurrentUser {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the episodes.
-    pub ids: Vec<String>,
+    pub ids: Vec<EpisodeId>,
 }
 
-impl<T, I> From<I> for SaveEpisodesforCurrentUser
+impl<I> From<I> for SaveEpisodesforCurrentUser
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = EpisodeId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl SaveEpisodesforCurrentUser {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<EpisodeId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for SaveEpisodesforCurrentUser {
     fn method(&self) -> Method {
         Method::PUT
@@ -32,9 +41,13 @@ impl Endpoint for SaveEpisodesforCurrentUser {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -56,8 +69,11 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint =
-            SaveEpisodesforCurrentUser::from(["77o6BIVlYM3msb4MMIL1jH", "0Q86acNRm6V9GYx55SXKwf"]);
+        let endpoint = SaveEpisodesforCurrentUser::from_ids([
+            "77o6BIVlYM3msb4MMIL1jH",
+            "0Q86acNRm6V9GYx55SXKwf",
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
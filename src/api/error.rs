@@ -14,6 +14,14 @@ pub enum BodyError {
     /// Body data could not be serialized to JSON from form parameters.
     #[error("failed to JSON encode form parameters: {0}")]
     JsonEncoded(#[from] serde_json::Error),
+
+    /// Image data is not in the format required by the endpoint.
+    #[error("invalid image format: expected a JPEG image")]
+    InvalidImageFormat,
+
+    /// Image data exceeds the maximum size accepted by the endpoint.
+    #[error("image is too large: {size} bytes, the maximum is {max} bytes")]
+    ImageTooLarge { size: usize, max: usize },
 }
 
 /// Errors which may occur when using API endpoints.
@@ -177,6 +185,21 @@ where
         }
     }
 
+    /// Creates an error from a failed response of an endpoint using the given URL base.
+    ///
+    /// Responses of the Accounts service are parsed as OAuth errors first.
+    pub(crate) fn from_response(
+        url_base: UrlBase,
+        status: http::StatusCode,
+        value: serde_json::Value,
+    ) -> Self {
+        match url_base {
+            UrlBase::ApiV1 => Self::from_spotify_with_status(status, value),
+            UrlBase::Accounts => oauth_error(&value)
+                .map_or_else(|| Self::from_spotify_with_status(status, value), Self::Auth),
+        }
+    }
+
     pub(crate) fn data_type<T>(source: serde_json::Error) -> Self {
         Self::DataType {
             source,
@@ -184,3 +207,17 @@ where
         }
     }
 }
+
+/// Parses an error returned by the Spotify Accounts service.
+///
+/// Unlike the Web API, the Accounts service reports errors in the OAuth 2.0 format:
+/// `{"error": "invalid_grant", "error_description": "Invalid authorization code"}`.
+fn oauth_error(value: &serde_json::Value) -> Option<AuthError> {
+    let error = value.get("error")?.as_str()?;
+    let description = value
+        .get("error_description")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned);
+
+    Some(AuthError::from_oauth_error(error, description))
+}
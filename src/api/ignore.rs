@@ -1,4 +1,7 @@
-use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query};
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query},
+    auth::scopes::RequiredScopes,
+};
 use async_trait::async_trait;
 use http::{
     Method, Request,
@@ -22,6 +25,8 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(&self.endpoint))?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -40,6 +45,10 @@ where
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(&url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.endpoint.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -54,7 +63,7 @@ where
         if !status.is_success() {
             let v = serde_json::from_slice(rsp.body())
                 .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.endpoint.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
@@ -70,6 +79,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(&self.endpoint))?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -88,6 +99,10 @@ where
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(&url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.endpoint.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -102,7 +117,7 @@ where
         if !status.is_success() {
             let v = serde_json::from_slice(rsp.body())
                 .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.endpoint.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
@@ -7,6 +7,33 @@ pub use lazy::*;
 pub use pagination::*;
 
 /// A trait to indicate that an endpoint is pageable.
-pub trait Pageable {}
+pub trait Pageable {
+    /// The field of the response holding the page, for endpoints that wrap the page in an
+    /// object such as `{"playlists": {...}}`.
+    ///
+    /// Defaults to `None`, meaning the response is the page itself.
+    fn page_key(&self) -> Option<&'static str> {
+        None
+    }
+}
 
-impl<E> Pageable for &E where E: Pageable {}
+impl<E> Pageable for &E
+where
+    E: Pageable,
+{
+    fn page_key(&self) -> Option<&'static str> {
+        (*self).page_key()
+    }
+}
+
+/// Takes the page out of the response of a pageable endpoint.
+pub(crate) fn page_value<E>(endpoint: &E, mut value: serde_json::Value) -> serde_json::Value
+where
+    E: Pageable,
+{
+    if let Some(page) = endpoint.page_key().and_then(|key| value.get_mut(key)) {
+        return page.take();
+    }
+
+    value
+}
@@ -1,6 +1,7 @@
-use super::{MAX_LIMIT, Pageable, Pagination};
+use super::{MAX_LIMIT, Pageable, Pagination, page_value};
 use crate::{
     api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query},
+    auth::scopes::RequiredScopes,
     model::Page,
 };
 use async_trait::async_trait;
@@ -69,6 +70,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(&self.endpoint))?;
+
         let url = {
             let mut url = self
                 .endpoint
@@ -106,6 +109,10 @@ where
                 .method(self.endpoint.method())
                 .uri(query::url_to_http_uri(&page_url));
 
+            if let Some(headers) = req.headers_mut() {
+                headers.extend(self.endpoint.headers());
+            }
+
             if let Some(mime) = mime {
                 req = req.header(header::CONTENT_TYPE, *mime);
             }
@@ -128,8 +135,8 @@ where
                 ));
             }
 
-            let page: Page<T> =
-                serde_json::from_value(v).map_err(ApiError::data_type::<Page<T>>)?;
+            let page: Page<T> = serde_json::from_value(page_value(&self.endpoint, v))
+                .map_err(ApiError::data_type::<Page<T>>)?;
 
             let page_len = page.items.len();
             next_url = page.next.as_ref().map(|url| Url::parse(url)).transpose()?;
@@ -1,7 +1,8 @@
 use self::query::{AsyncQuery, Query};
-use super::{Pageable, Paged, Pagination};
+use super::{Pageable, Paged, Pagination, page_value};
 use crate::{
     api::{ApiError, AsyncClient, Client, Endpoint, RestClient, query},
+    auth::scopes::RequiredScopes,
     model::Page,
 };
 use async_trait::async_trait;
@@ -101,6 +102,8 @@ where
         let url = if let Some(next_url) = next_page.next_url() {
             next_url.clone()
         } else {
+            client.check_scopes(&RequiredScopes::of(&self.paged.endpoint))?;
+
             let mut url = self
                 .paged
                 .endpoint
@@ -133,6 +136,10 @@ where
             .method(self.paged.endpoint.method())
             .uri(query::url_to_http_uri(url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.paged.endpoint.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -163,7 +170,8 @@ where
             ));
         }
 
-        let page = serde_json::from_value::<Page<T>>(v).map_err(ApiError::data_type::<Page<T>>)?;
+        let page = serde_json::from_value::<Page<T>>(page_value(&self.paged.endpoint, v))
+            .map_err(ApiError::data_type::<Page<T>>)?;
 
         let next_url = page.next.as_ref().map(|url| Url::parse(url)).transpose()?;
 
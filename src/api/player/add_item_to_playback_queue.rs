@@ -1,4 +1,7 @@
-use crate::{api::prelude::*, model::PlaylistItem};
+use crate::{
+    api::prelude::*,
+    model::{EpisodeId, PlaylistItem, TrackId},
+};
 
 /// Add an item to the end of the user's current playback queue.
 /// This API only works for users who have Spotify Premium.
@@ -21,6 +24,25 @@ impl From<PlaylistItem> for AddItemToPlaybackQueue {
     }
 }
 
+impl From<TrackId> for AddItemToPlaybackQueue {
+    fn from(track: TrackId) -> Self {
+        Self::from(PlaylistItem::from(track))
+    }
+}
+
+impl From<EpisodeId> for AddItemToPlaybackQueue {
+    fn from(episode: EpisodeId) -> Self {
+        Self::from(PlaylistItem::from(episode))
+    }
+}
+
+impl AddItemToPlaybackQueue {
+    pub fn device_id(mut self, device_id: impl Into<String>) -> Self {
+        self.device_id = Some(device_id.into());
+        self
+    }
+}
+
 impl Endpoint for AddItemToPlaybackQueue {
     fn method(&self) -> Method {
         Method::POST
@@ -36,6 +58,10 @@ impl Endpoint for AddItemToPlaybackQueue {
         params.push("uri", &self.uri);
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -43,7 +69,6 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        model::TrackId,
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -62,4 +87,23 @@ mod tests {
             .query(&client)
             .unwrap();
     }
+
+    #[test]
+    fn test_add_episode_to_playback_queue_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("me/player/queue")
+            .add_query_params(&[
+                ("device_id", "xxxxxxxxxxxxxxxxxxxxxx"),
+                ("uri", "spotify:episode:512ojhOuo1ktJprKbVcKyQ"),
+            ])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint =
+            AddItemToPlaybackQueue::from(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap())
+                .device_id("xxxxxxxxxxxxxxxxxxxxxx");
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
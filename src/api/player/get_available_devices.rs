@@ -13,6 +13,10 @@ impl Endpoint for GetAvailableDevices {
     fn endpoint(&self) -> Cow<'static, str> {
         "me/player/devices".into()
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserReadPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
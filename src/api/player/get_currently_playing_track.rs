@@ -1,4 +1,10 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{
+        common::{comma_separated, push_unique},
+        prelude::*,
+    },
+    model::AdditionalType,
+};
 
 /// Get the object currently being played on the user's Spotify account.
 #[derive(Debug, Default, Clone)]
@@ -11,6 +17,26 @@ pub struct GetCurrentlyPlayingTrack {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// A list of item types that your client supports besides the default `track` type.
+    /// Without `episode`, the item is `None` while a podcast episode is playing.
+    ///
+    /// # Notes
+    /// This parameter was introduced to allow existing clients to maintain their current behaviour
+    /// and might be deprecated in the future.
+    pub additional_types: Option<Vec<AdditionalType>>,
+}
+
+impl GetCurrentlyPlayingTrack {
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    pub fn additional_type(mut self, additional_type: AdditionalType) -> Self {
+        push_unique(&mut self.additional_types, additional_type);
+        self
+    }
 }
 
 impl Endpoint for GetCurrentlyPlayingTrack {
@@ -25,8 +51,16 @@ impl Endpoint for GetCurrentlyPlayingTrack {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt(
+            "additional_types",
+            self.additional_types.as_deref().map(comma_separated),
+        );
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserReadCurrentlyPlaying.into()
+    }
 }
 
 #[cfg(test)]
@@ -49,4 +83,21 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_currently_playing_track_endpoint_with_additional_types() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player/currently-playing")
+            .add_query_params(&[("market", "ES"), ("additional_types", "track,episode")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetCurrentlyPlayingTrack::default()
+            .market("ES".to_owned())
+            .additional_type(AdditionalType::Track)
+            .additional_type(AdditionalType::Episode);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
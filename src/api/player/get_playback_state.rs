@@ -1,4 +1,10 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{
+        common::{comma_separated, push_unique},
+        prelude::*,
+    },
+    model::AdditionalType,
+};
 
 /// Get information about the user’s current playback state, including track or episode, progress, and active device.
 #[derive(Debug, Default, Clone)]
@@ -11,6 +17,26 @@ pub struct GetPlaybackState {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// A list of item types that your client supports besides the default `track` type.
+    /// Without `episode`, the item is `None` while a podcast episode is playing.
+    ///
+    /// # Notes
+    /// This parameter was introduced to allow existing clients to maintain their current behaviour
+    /// and might be deprecated in the future.
+    pub additional_types: Option<Vec<AdditionalType>>,
+}
+
+impl GetPlaybackState {
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    pub fn additional_type(mut self, additional_type: AdditionalType) -> Self {
+        push_unique(&mut self.additional_types, additional_type);
+        self
+    }
 }
 
 impl Endpoint for GetPlaybackState {
@@ -25,8 +51,16 @@ impl Endpoint for GetPlaybackState {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt(
+            "additional_types",
+            self.additional_types.as_deref().map(comma_separated),
+        );
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserReadPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -47,4 +81,37 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_playback_state_endpoint_with_additional_types() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player")
+            .add_query_params(&[("market", "ES"), ("additional_types", "track,episode")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaybackState::default()
+            .market("ES".to_owned())
+            .additional_type(AdditionalType::Track)
+            .additional_type(AdditionalType::Episode);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playback_state_endpoint_with_repeated_additional_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player")
+            .add_query_params(&[("additional_types", "episode")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaybackState::default()
+            .additional_type(AdditionalType::Episode)
+            .additional_type(AdditionalType::Episode);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -42,6 +42,10 @@ impl Endpoint for GetRecentlyPlayedTracks {
 
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserReadRecentlyPlayed.into()
+    }
 }
 
 impl From<QueryRange> for GetRecentlyPlayedTracks {
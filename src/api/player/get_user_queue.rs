@@ -1,8 +1,29 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{
+        common::{comma_separated, push_unique},
+        prelude::*,
+    },
+    model::AdditionalType,
+};
 
 /// Get the list of objects that make up the user's queue.
 #[derive(Default, Debug, Clone)]
-pub struct GetUserQueue;
+pub struct GetUserQueue {
+    /// A list of item types that your client supports besides the default `track` type.
+    /// Include `episode` to receive queued and playing podcast episodes as [`TrackItem::Episode`](crate::model::TrackItem::Episode).
+    ///
+    /// # Notes
+    /// This parameter was introduced to allow existing clients to maintain their current behaviour
+    /// and might be deprecated in the future.
+    pub additional_types: Option<Vec<AdditionalType>>,
+}
+
+impl GetUserQueue {
+    pub fn additional_type(mut self, additional_type: AdditionalType) -> Self {
+        push_unique(&mut self.additional_types, additional_type);
+        self
+    }
+}
 
 impl Endpoint for GetUserQueue {
     fn method(&self) -> Method {
@@ -12,6 +33,22 @@ impl Endpoint for GetUserQueue {
     fn endpoint(&self) -> Cow<'static, str> {
         "me/player/queue".into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt(
+            "additional_types",
+            self.additional_types.as_deref().map(comma_separated),
+        );
+        params
+    }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        HashSet::from([
+            Scope::UserReadCurrentlyPlaying,
+            Scope::UserReadPlaybackState,
+        ])
+    }
 }
 
 #[cfg(test)]
@@ -26,6 +63,17 @@ mod tests {
     fn test_get_user_queue_endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("me/player/queue").build();
         let client = SingleTestClient::new_raw(endpoint, "");
-        api::ignore(GetUserQueue).query(&client).unwrap();
+        api::ignore(GetUserQueue::default()).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_user_queue_endpoint_with_additional_types() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player/queue")
+            .add_query_params(&[("additional_types", "episode")])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+        let endpoint = GetUserQueue::default().additional_type(AdditionalType::Episode);
+        api::ignore(endpoint).query(&client).unwrap();
     }
 }
@@ -31,6 +31,10 @@ impl Endpoint for PausePlayback {
         params.push_opt("devide_id", self.device_id.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +37,10 @@ impl Endpoint for SeekToPosition {
         params.push_opt("device_id", self.device_id.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -30,6 +30,10 @@ impl Endpoint for SetPlaybackVolume {
         );
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 impl From<u8> for SetPlaybackVolume {
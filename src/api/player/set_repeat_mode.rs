@@ -36,6 +36,10 @@ impl Endpoint for SetRepeatMode {
         params.push_opt("device_id", self.device_id.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::{ContextType, Offset, TrackId},
+    model::{ContextType, Offset, PlaylistItem},
 };
 
 /// Start a new context or resume current playback on the user's active device.
@@ -14,8 +14,8 @@ pub struct StartPlayback {
     /// Spotify URI of the context to play.
     pub context_uri: Option<ContextType>,
 
-    /// Spotify track URIs to play.
-    pub uris: Option<Vec<TrackId>>,
+    /// Spotify track or episode URIs to play.
+    pub uris: Option<Vec<PlaylistItem>>,
 
     /// Indicates from where in the context playback should start.
     pub offset: Option<Offset>,
@@ -35,13 +35,13 @@ impl StartPlayback {
         self
     }
 
-    pub fn uris(mut self, uris: Vec<TrackId>) -> Self {
-        self.uris = Some(uris);
+    pub fn uris(mut self, uris: impl IntoIterator<Item = impl Into<PlaylistItem>>) -> Self {
+        self.uris = Some(uris.into_iter().map(Into::into).collect());
         self
     }
 
-    pub fn uri(mut self, uri: TrackId) -> Self {
-        self.uris.get_or_insert_with(Vec::new).push(uri);
+    pub fn uri(mut self, uri: impl Into<PlaylistItem>) -> Self {
+        self.uris.get_or_insert_with(Vec::new).push(uri.into());
         self
     }
 
@@ -91,7 +91,11 @@ impl Endpoint for StartPlayback {
         }
 
         if let Some(uris) = &self.uris {
-            body["uris"] = uris.iter().map(|uri| uri.uri()).collect::<Vec<_>>().into();
+            body["uris"] = uris
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .into();
         }
 
         if let Some(offset) = self.offset.as_ref() {
@@ -99,7 +103,7 @@ impl Endpoint for StartPlayback {
                 Offset::Position(pos) => {
                     serde_json::json!({ "position": pos })
                 }
-                Offset::Uri(context) => serde_json::json!({ "uri": context.uri() }),
+                Offset::Uri(item) => serde_json::json!({ "uri": item.to_string() }),
             }
         }
 
@@ -113,6 +117,10 @@ impl Endpoint for StartPlayback {
 
         JsonParams::into_body(&body)
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +128,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        model::AlbumId,
+        model::{AlbumId, EpisodeId, PlaylistId, TrackId},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -161,4 +169,42 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_start_playback_endpoint_with_tracks_and_episodes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("me/player/play")
+            .body_str(r#"{"uris":["spotify:track:4iV5W9uYEdYUVa79Axb7Rh","spotify:episode:512ojhOuo1ktJprKbVcKyQ"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StartPlayback::default()
+            .uri(TrackId::from_id("4iV5W9uYEdYUVa79Axb7Rh").unwrap())
+            .uri(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap());
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_start_playback_endpoint_with_uri_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("me/player/play")
+            .body_str(r#"{"context_uri":"spotify:playlist:3cEYpjA9oz9GiPac4AsH4n","offset":{"uri":"spotify:episode:512ojhOuo1ktJprKbVcKyQ"}}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StartPlayback::default()
+            .context_uri(ContextType::Playlist(
+                PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            ))
+            .offset(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap());
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -36,6 +36,10 @@ impl Endpoint for TogglePlaybackShuffle {
         params.push("state", &self.state);
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -40,6 +40,10 @@ impl Endpoint for TransferPlayback {
             "play": self.play,
         }))
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserModifyPlaybackState.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,13 +1,13 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::PlaylistItem,
+    model::{PlaylistId, PlaylistItem},
 };
 
 /// Add one or more items to a user's playlist.
 #[derive(Debug, Clone)]
 pub struct AddItemsToPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// The position to insert the items, a zero-based index.
     /// For example, to insert the items in the first position: position=0; to insert the items in the third position: position=2.
@@ -25,7 +25,7 @@ impl Endpoint for AddItemsToPlaylist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/tracks", self.id).into()
+        format!("playlists/{}/tracks", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -41,6 +41,10 @@ impl Endpoint for AddItemsToPlaylist {
         params.push_opt("position", self.position);
         params
     }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
 }
 
 #[cfg(test)]
@@ -65,7 +69,7 @@ mod tests {
         let track = TrackId::from_id("60zbztYPxtTQLLcPVjnEZG").unwrap();
 
         let endpoint = AddItemsToPlaylist {
-            id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
+            id: PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
             position: None,
             uris: vec![track.into()],
         };
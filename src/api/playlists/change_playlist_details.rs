@@ -1,11 +1,14 @@
-use crate::api::{Endpoint, prelude::*};
+use crate::{
+    api::{Endpoint, prelude::*},
+    model::PlaylistId,
+};
 use serde_json::json;
 
 /// Change a playlist's name and public/private state. (The user must, of course, own the playlist.)
 #[derive(Debug, Clone)]
 pub struct ChangePlaylistDetails {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// The new name for the playlist, for example "My New Playlist Title".
     pub name: Option<String>,
@@ -26,9 +29,9 @@ pub struct ChangePlaylistDetails {
 }
 
 impl ChangePlaylistDetails {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: PlaylistId) -> Self {
         Self {
-            id: id.into(),
+            id,
             name: None,
             public: None,
             collaborative: None,
@@ -43,7 +46,7 @@ impl Endpoint for ChangePlaylistDetails {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}", self.id).into()
+        format!("playlists/{}", self.id.id()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -67,6 +70,10 @@ impl Endpoint for ChangePlaylistDetails {
 
         JsonParams::into_body(&body)
     }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
 }
 
 #[cfg(test)]
@@ -89,7 +96,7 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint = ChangePlaylistDetails {
-            id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
+            id: PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
             name: Some("Updated Playlist Name".to_owned()),
             description: Some("New Playlist Description".to_owned()),
             public: Some(false),
@@ -1,4 +1,7 @@
-use crate::api::{Endpoint, prelude::*};
+use crate::{
+    api::{Endpoint, prelude::*},
+    model::UserId,
+};
 use serde_json::json;
 
 /// Create a playlist for a Spotify user.
@@ -7,7 +10,7 @@ use serde_json::json;
 #[derive(Debug, Clone)]
 pub struct CreatePlaylist {
     /// The user's [Spotify user ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids).
-    pub id: String,
+    pub id: UserId,
 
     /// The new name for the playlist, for example "My New Playlist Title".
     pub name: String,
@@ -33,7 +36,7 @@ impl Endpoint for CreatePlaylist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/playlists", self.id).into()
+        format!("users/{}/playlists", self.id.id()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -55,6 +58,21 @@ impl Endpoint for CreatePlaylist {
 
         JsonParams::into_body(&body)
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        match self.public {
+            Some(true) => Scope::PlaylistModifyPublic.into(),
+            Some(false) => Scope::PlaylistModifyPrivate.into(),
+            None => HashSet::new(),
+        }
+    }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        match self.public {
+            Some(_) => HashSet::new(),
+            None => scopes::playlist_modify(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +95,7 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint = CreatePlaylist {
-            id: "smedjan".to_owned(),
+            id: UserId::from_id("smedjan").unwrap(),
             name: "New Playlist".to_owned(),
             description: Some("New playlist description".to_owned()),
             public: Some(false),
@@ -0,0 +1,92 @@
+use crate::api::prelude::*;
+
+/// Get a list of Spotify playlists tagged with a particular category.
+#[derive(Debug, Clone)]
+pub struct GetCategoryPlaylists {
+    /// The [Spotify category ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the category.
+    pub id: String,
+}
+
+impl GetCategoryPlaylists {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self::from(id)
+    }
+}
+
+impl<T: Into<String>> From<T> for GetCategoryPlaylists {
+    fn from(id: T) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+impl Pageable for GetCategoryPlaylists {
+    fn page_key(&self) -> Option<&'static str> {
+        Some("playlists")
+    }
+}
+
+impl Endpoint for GetCategoryPlaylists {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("browse/categories/{}/playlists", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::{FeaturedPlaylists, SimplifiedPlaylist},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    const RESPONSE: &str = r#"
+    {
+        "message": "Pop",
+        "playlists": {
+            "href": "https://api.spotify.com/v1/browse/categories/dinner/playlists?offset=0&limit=20",
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 0,
+            "items": []
+        }
+    }
+    "#;
+
+    #[test]
+    fn test_get_category_playlists_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/categories/dinner/playlists")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, RESPONSE);
+
+        let playlists: FeaturedPlaylists =
+            GetCategoryPlaylists::from("dinner").query(&client).unwrap();
+
+        assert_eq!(playlists.message, "Pop");
+    }
+
+    #[test]
+    fn test_get_category_playlists_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/categories/dinner/playlists")
+            .paginated(true)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, RESPONSE);
+
+        let playlists: Vec<SimplifiedPlaylist> =
+            api::paged_all(GetCategoryPlaylists::from("dinner"))
+                .query(&client)
+                .unwrap();
+
+        assert!(playlists.is_empty());
+    }
+}
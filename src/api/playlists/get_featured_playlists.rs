@@ -0,0 +1,155 @@
+use crate::api::prelude::*;
+
+/// Get a list of Spotify featured playlists (shown, for example, on a Spotify player's 'Browse' tab).
+#[derive(Debug, Default, Clone)]
+pub struct GetFeaturedPlaylists {
+    /// The desired language, consisting of an [ISO 639-1](http://en.wikipedia.org/wiki/ISO_639-1) language code and an [ISO 3166-1 alpha-2 country code](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2), joined by an underscore. For example: `es_MX`, meaning "Spanish (Mexico)". Provide this parameter if you want the results returned in a particular language (where available).
+    ///
+    /// # Notes
+    /// if locale is not supplied, or if the specified language is not available, all strings will be returned in the Spotify default language (American English).
+    ///
+    /// Example: `sv_SE`
+    pub locale: Option<String>,
+
+    /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
+    /// If a country code is specified, only content that is available in that market will be returned.
+    pub market: Option<Market>,
+
+    /// A timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `yyyy-MM-ddTHH:mm:ss`.
+    /// Use this parameter to specify the user's local time to get results tailored for that specific date and time in the day.
+    /// If not provided, the response defaults to the current UTC time.
+    ///
+    /// Example: `2014-10-23T09:00:00`
+    pub timestamp: Option<String>,
+}
+
+impl GetFeaturedPlaylists {
+    pub fn with_locale(locale: impl Into<String>) -> Self {
+        Self {
+            locale: Some(locale.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Pageable for GetFeaturedPlaylists {
+    fn page_key(&self) -> Option<&'static str> {
+        Some("playlists")
+    }
+}
+
+impl Endpoint for GetFeaturedPlaylists {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "browse/featured-playlists".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("locale", self.locale.as_ref());
+        params.push_opt("market", self.market.as_ref());
+        params.push_opt("timestamp", self.timestamp.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::{FeaturedPlaylists, SimplifiedPlaylist},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    const RESPONSE: &str = r#"
+    {
+        "message": "Popular Playlists",
+        "playlists": {
+            "href": "https://api.spotify.com/v1/browse/featured-playlists?offset=0&limit=20",
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1,
+            "items": [
+                {
+                    "collaborative": false,
+                    "description": "string",
+                    "external_urls": {
+                        "spotify": "string"
+                    },
+                    "href": "string",
+                    "id": "37i9dQZF1DXcBWIGoYBM5M",
+                    "images": [],
+                    "name": "Today's Top Hits",
+                    "owner": {
+                        "external_urls": {
+                            "spotify": "string"
+                        },
+                        "href": "string",
+                        "id": "spotify",
+                        "type": "user",
+                        "uri": "spotify:user:spotify",
+                        "display_name": "Spotify"
+                    },
+                    "primary_color": null,
+                    "public": true,
+                    "snapshot_id": "string",
+                    "tracks": {
+                        "href": "string",
+                        "total": 50
+                    },
+                    "type": "playlist",
+                    "uri": "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M"
+                }
+            ]
+        }
+    }
+    "#;
+
+    #[test]
+    fn test_get_featured_playlists_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/featured-playlists")
+            .add_query_params(&[
+                ("locale", "sv_SE"),
+                ("market", "SE"),
+                ("timestamp", "2014-10-23T09:00:00"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, RESPONSE);
+
+        let endpoint = GetFeaturedPlaylists {
+            locale: Some("sv_SE".to_owned()),
+            market: Some("SE".to_owned()),
+            timestamp: Some("2014-10-23T09:00:00".to_owned()),
+        };
+
+        let featured: FeaturedPlaylists = endpoint.query(&client).unwrap();
+
+        assert_eq!(featured.message, "Popular Playlists");
+        assert_eq!(featured.playlists.items.len(), 1);
+    }
+
+    #[test]
+    fn test_get_featured_playlists_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/featured-playlists")
+            .paginated(true)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, RESPONSE);
+
+        let playlists: Vec<SimplifiedPlaylist> = api::paged_all(GetFeaturedPlaylists::default())
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(playlists[0].id, "37i9dQZF1DXcBWIGoYBM5M");
+    }
+}
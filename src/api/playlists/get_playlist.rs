@@ -1,11 +1,17 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{
+        common::{comma_separated, push_unique},
+        prelude::*,
+    },
+    model::{AdditionalType, Fields, IdError, PlaylistId},
+};
 
 /// Get a playlist owned by a Spotify user.
 #[derive(Debug, Clone)]
 // #[endpoint(method = GET, path = "playlists/{id}")]
 pub struct GetPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -15,29 +21,96 @@ pub struct GetPlaylist {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Filters for the query: a selection of the fields to return. If omitted or empty, all fields are returned.
+    ///
+    /// Pair this with your own `Deserialize` types in place of the predefined models, which expect every field,
+    /// to transfer and parse only the data you need. See [`Fields`] for the syntax.
+    pub fields: Option<Fields>,
+
+    /// A list of item types that your client supports besides the default `track` type.
+    ///
+    /// # Notes
+    /// This parameter was introduced to allow existing clients to maintain their current behaviour
+    /// and might be deprecated in the future.
+    pub additional_types: Option<Vec<AdditionalType>>,
 }
 
-impl<T: Into<String>> From<T> for GetPlaylist {
-    fn from(id: T) -> Self {
+impl From<PlaylistId> for GetPlaylist {
+    fn from(id: PlaylistId) -> Self {
         Self {
-            id: id.into(),
+            id,
             market: None,
+            fields: None,
+            additional_types: None,
         }
     }
 }
 
+impl TryFrom<&str> for GetPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl GetPlaylist {
+    /// Creates the endpoint for a playlist.
+    pub fn new(id: PlaylistId) -> Self {
+        Self::from(id)
+    }
+
+    /// Sets the market to return content available in.
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    /// Sets the fields to return. An empty selection returns every field.
+    pub fn fields(mut self, fields: Fields) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Adds an item type the client supports besides `track`, such as `episode`.
+    pub fn additional_type(mut self, additional_type: AdditionalType) -> Self {
+        push_unique(&mut self.additional_types, additional_type);
+        self
+    }
+}
+
 impl Endpoint for GetPlaylist {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}", self.id).into()
+        format!("playlists/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt(
+            "fields",
+            self.fields
+                .as_ref()
+                .filter(|fields| !fields.is_empty())
+                .map(ToString::to_string),
+        );
+        params.push_opt(
+            "additional_types",
+            self.additional_types.as_deref().map(comma_separated),
+        );
         params
     }
 }
@@ -58,7 +131,52 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetPlaylist::from("3cEYpjA9oz9GiPac4AsH4n");
+        let endpoint = GetPlaylist::try_from("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playlist_endpoint_with_fields() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n")
+            .add_query_params(&[
+                ("market", "US"),
+                (
+                    "fields",
+                    "name,tracks.items(track(name,!available_markets))",
+                ),
+                ("additional_types", "episode"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylist::new(PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap())
+            .market("US".to_owned())
+            .fields(Fields::new().field("name").nested(
+                "tracks.items",
+                Fields::new().nested(
+                    "track",
+                    Fields::new().field("name").exclude("available_markets"),
+                ),
+            ))
+            .additional_type(AdditionalType::Episode);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playlist_endpoint_with_empty_fields() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylist::try_from("3cEYpjA9oz9GiPac4AsH4n")
+            .unwrap()
+            .fields(Fields::new());
 
         api::ignore(endpoint).query(&client).unwrap();
     }
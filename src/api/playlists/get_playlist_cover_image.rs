@@ -1,21 +1,40 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, PlaylistId},
+};
 
 /// Get the current image associated with a specific playlist.
 #[derive(Debug, Clone)]
 pub struct GetPlaylistCoverImage {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 }
 
 impl GetPlaylistCoverImage {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: PlaylistId) -> Self {
         Self::from(id)
     }
 }
 
-impl<T: Into<String>> From<T> for GetPlaylistCoverImage {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<PlaylistId> for GetPlaylistCoverImage {
+    fn from(id: PlaylistId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetPlaylistCoverImage {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetPlaylistCoverImage {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
     }
 }
 
@@ -25,7 +44,7 @@ impl Endpoint for GetPlaylistCoverImage {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/images", self.id).into()
+        format!("playlists/{}/images", self.id.id()).into()
     }
 }
 
@@ -45,7 +64,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetPlaylistCoverImage::from("3cEYpjA9oz9GiPac4AsH4n");
+        let endpoint = GetPlaylistCoverImage::try_from("3cEYpjA9oz9GiPac4AsH4n").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,10 +1,16 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{
+        common::{comma_separated, push_unique},
+        prelude::*,
+    },
+    model::{AdditionalType, Fields, IdError, PlaylistId},
+};
 
 /// Get full details of the items of a playlist owned by a Spotify user.
 #[derive(Debug, Clone)]
 pub struct GetPlaylistItems {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -14,31 +20,100 @@ pub struct GetPlaylistItems {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Filters for the query: a selection of the fields to return. If omitted or empty, all fields are returned.
+    ///
+    /// Pair this with your own `Deserialize` types in place of the predefined models, which expect every field,
+    /// to transfer and parse only the data you need. See [`Fields`] for the syntax.
+    /// When paging through the items with [`api::paged`](crate::api::paged), build the selection with
+    /// [`Fields::page`] so that every response still contains the page details.
+    pub fields: Option<Fields>,
+
+    /// A list of item types that your client supports besides the default `track` type.
+    ///
+    /// # Notes
+    /// This parameter was introduced to allow existing clients to maintain their current behaviour
+    /// and might be deprecated in the future.
+    pub additional_types: Option<Vec<AdditionalType>>,
 }
 
 impl Pageable for GetPlaylistItems {}
 
-impl<T: Into<String>> From<T> for GetPlaylistItems {
-    fn from(id: T) -> Self {
+impl From<PlaylistId> for GetPlaylistItems {
+    fn from(id: PlaylistId) -> Self {
         Self {
-            id: id.into(),
+            id,
             market: None,
+            fields: None,
+            additional_types: None,
         }
     }
 }
 
+impl TryFrom<&str> for GetPlaylistItems {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetPlaylistItems {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl GetPlaylistItems {
+    /// Creates the endpoint for the items of a playlist.
+    pub fn new(id: PlaylistId) -> Self {
+        Self::from(id)
+    }
+
+    /// Sets the market to return content available in.
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    /// Sets the fields to return. An empty selection returns every field.
+    pub fn fields(mut self, fields: Fields) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Adds an item type the client supports besides `track`, such as `episode`.
+    pub fn additional_type(mut self, additional_type: AdditionalType) -> Self {
+        push_unique(&mut self.additional_types, additional_type);
+        self
+    }
+}
+
 impl Endpoint for GetPlaylistItems {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/tracks", self.id).into()
+        format!("playlists/{}/tracks", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt(
+            "fields",
+            self.fields
+                .as_ref()
+                .filter(|fields| !fields.is_empty())
+                .map(ToString::to_string),
+        );
+        params.push_opt(
+            "additional_types",
+            self.additional_types.as_deref().map(comma_separated),
+        );
         params
     }
 }
@@ -59,7 +134,50 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetPlaylistItems::from("3cEYpjA9oz9GiPac4AsH4n");
+        let endpoint = GetPlaylistItems::try_from("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playlist_items_endpoint_with_fields() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .add_query_params(&[
+                (
+                    "fields",
+                    "href,limit,next,offset,previous,total,items(added_at,track(name,uri))",
+                ),
+                ("additional_types", "track,episode"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint =
+            GetPlaylistItems::new(PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap())
+                .fields(Fields::page(
+                    Fields::new()
+                        .field("added_at")
+                        .nested("track", Fields::new().field("name").field("uri")),
+                ))
+                .additional_type(AdditionalType::Track)
+                .additional_type(AdditionalType::Episode);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playlist_items_endpoint_with_empty_fields() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylistItems::try_from("3cEYpjA9oz9GiPac4AsH4n")
+            .unwrap()
+            .fields(Fields::new());
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,23 +1,42 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, UserId},
+};
 
 /// Get a list of the playlists owned or followed by a Spotify user.
 #[derive(Debug, Clone)]
 pub struct GetUserPlaylists {
     /// The user's [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids).
-    pub id: String,
+    pub id: UserId,
 }
 
 impl GetUserPlaylists {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: UserId) -> Self {
         Self::from(id)
     }
 }
 
 impl Pageable for GetUserPlaylists {}
 
-impl<T: Into<String>> From<T> for GetUserPlaylists {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<UserId> for GetUserPlaylists {
+    fn from(id: UserId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetUserPlaylists {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        UserId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetUserPlaylists {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        UserId::from_id(id).map(Self::from)
     }
 }
 
@@ -27,7 +46,7 @@ impl Endpoint for GetUserPlaylists {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/playlists", self.id).into()
+        format!("users/{}/playlists", self.id.id()).into()
     }
 }
 
@@ -47,7 +66,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetUserPlaylists::new("smedjan");
+        let endpoint = GetUserPlaylists::new(UserId::from_id("smedjan").unwrap());
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,6 +1,6 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::PlaylistItem,
+    model::{PlaylistId, PlaylistItem},
 };
 use serde_json::json;
 
@@ -8,7 +8,7 @@ use serde_json::json;
 #[derive(Debug, Clone)]
 pub struct RemovePlaylistItems {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// A list of [Spotify URIs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) to set, can be track or episode URIs.
     pub tracks: Vec<PlaylistItem>,
@@ -24,7 +24,7 @@ impl Endpoint for RemovePlaylistItems {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/tracks", self.id).into()
+        format!("playlists/{}/tracks", self.id.id()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -45,6 +45,10 @@ impl Endpoint for RemovePlaylistItems {
 
         JsonParams::into_body(&body)
     }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +75,7 @@ mod tests {
         let track_2 = TrackId::from_id("1301WleyT98MSxVHPZCA6M").unwrap();
 
         let endpoint = RemovePlaylistItems {
-            id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
+            id: PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
             snapshot_id: "abc".to_owned(),
             tracks: vec![track_1.into(), track_2.into()],
         };
@@ -1,6 +1,6 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::PlaylistItem,
+    model::{PlaylistId, PlaylistItem},
 };
 use serde_json::json;
 
@@ -12,7 +12,7 @@ use serde_json::json;
 #[derive(Debug, Clone)]
 pub struct UpdatePlaylistItems {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// A list of [Spotify URIs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) to set, can be track or episode URIs.
     pub uris: Option<Vec<PlaylistItem>>,
@@ -31,9 +31,9 @@ pub struct UpdatePlaylistItems {
 }
 
 impl UpdatePlaylistItems {
-    pub fn new(id: impl Into<String>, range_start: u32, insert_before: u32) -> Self {
+    pub fn new(id: PlaylistId, range_start: u32, insert_before: u32) -> Self {
         Self {
-            id: id.into(),
+            id,
             uris: None,
             range_start,
             insert_before,
@@ -69,7 +69,7 @@ impl Endpoint for UpdatePlaylistItems {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/tracks", self.id).into()
+        format!("playlists/{}/tracks", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -99,6 +99,10 @@ impl Endpoint for UpdatePlaylistItems {
 
         JsonParams::into_body(&body)
     }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +124,9 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = UpdatePlaylistItems::new("3cEYpjA9oz9GiPac4AsH4n", 1, 3).range_length(2);
+        let endpoint =
+            UpdatePlaylistItems::new(PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(), 1, 3)
+                .range_length(2);
 
         api::ignore(endpoint).query(&client).unwrap();
     }
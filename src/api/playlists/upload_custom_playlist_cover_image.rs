@@ -0,0 +1,147 @@
+use crate::{api::prelude::*, model::PlaylistId};
+use base64::{Engine as _, engine::general_purpose};
+
+/// The maximum size of the Base64 encoded image data accepted by Spotify.
+pub const MAX_COVER_IMAGE_SIZE: usize = 256 * 1024;
+
+/// The bytes every JPEG image starts with.
+const JPEG_MAGIC_BYTES: [u8; 3] = [0xFF, 0xD8, 0xFF];
+
+/// Replace the image used to represent a specific playlist.
+///
+/// The image must be a JPEG, and its Base64 encoding must not exceed 256 KB.
+/// Both are checked before the request is sent.
+#[derive(Debug, Clone)]
+pub struct UploadCustomPlaylistCoverImage {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
+    pub id: PlaylistId,
+
+    /// The JPEG image data.
+    pub image: Vec<u8>,
+}
+
+impl UploadCustomPlaylistCoverImage {
+    pub fn new(id: PlaylistId, image: impl Into<Vec<u8>>) -> Self {
+        Self {
+            id,
+            image: image.into(),
+        }
+    }
+}
+
+impl Endpoint for UploadCustomPlaylistCoverImage {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("playlists/{}/images", self.id.id()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        if !self.image.starts_with(&JPEG_MAGIC_BYTES) {
+            return Err(BodyError::InvalidImageFormat);
+        }
+
+        let data = general_purpose::STANDARD.encode(&self.image).into_bytes();
+
+        if data.len() > MAX_COVER_IMAGE_SIZE {
+            return Err(BodyError::ImageTooLarge {
+                size: data.len(),
+                max: MAX_COVER_IMAGE_SIZE,
+            });
+        }
+
+        Ok(Some(("image/jpeg", data)))
+    }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UgcImageUpload.into()
+    }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, ApiError, AsyncQuery as _, Query as _},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    const IMAGE: [u8; 6] = [0xFF, 0xD8, 0xFF, 0xE0, 0xFF, 0xD9];
+
+    #[test]
+    fn test_upload_custom_playlist_cover_image_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("image/jpeg")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/images")
+            .body_str("/9j/4P/Z")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadCustomPlaylistCoverImage::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            IMAGE,
+        );
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_custom_playlist_cover_image_endpoint_async() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("image/jpeg")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/images")
+            .body_str("/9j/4P/Z")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadCustomPlaylistCoverImage::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            IMAGE,
+        );
+
+        api::ignore(endpoint).query_async(&client).await.unwrap();
+    }
+
+    #[test]
+    fn test_upload_custom_playlist_cover_image_validation() {
+        let endpoint = UploadCustomPlaylistCoverImage::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            b"\x89PNG\r\n".to_vec(),
+        );
+        assert!(matches!(
+            endpoint.body(),
+            Err(BodyError::InvalidImageFormat)
+        ));
+
+        let mut image = IMAGE.to_vec();
+        image.resize(MAX_COVER_IMAGE_SIZE, 0);
+        let endpoint = UploadCustomPlaylistCoverImage::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            image,
+        );
+        assert!(matches!(
+            endpoint.body(),
+            Err(BodyError::ImageTooLarge {
+                max: MAX_COVER_IMAGE_SIZE,
+                ..
+            })
+        ));
+
+        let client = SingleTestClient::new_raw(ExpectedUrl::builder().build(), "");
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        assert!(matches!(
+            err,
+            ApiError::Body(BodyError::ImageTooLarge { .. })
+        ));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query};
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query},
+    auth::scopes::RequiredScopes,
+};
 use async_trait::async_trait;
 use http::{Method, Request, header};
 
@@ -19,15 +22,21 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(&self.endpoint))?;
+
         let mut url = self
             .endpoint
             .url_base()
             .endpoint_for(client, &self.endpoint.endpoint())?;
         self.endpoint.parameters().add_to_url(&mut url);
 
-        let req = Request::builder()
+        let mut req = Request::builder()
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(&url));
+
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.endpoint.headers());
+        }
         let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
             let req = req.header(header::CONTENT_TYPE, mime);
             (req, data)
@@ -39,7 +48,7 @@ where
         if !status.is_success() {
             let v = serde_json::from_slice(rsp.body())
                 .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.endpoint.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(header::LOCATION),
@@ -57,6 +66,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
+        client.check_scopes(&RequiredScopes::of(&self.endpoint))?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -75,6 +86,10 @@ where
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(&url));
 
+        if let Some(headers) = req.headers_mut() {
+            headers.extend(self.endpoint.headers());
+        }
+
         if let Some(mime) = mime {
             req = req.header(header::CONTENT_TYPE, mime);
         }
@@ -89,7 +104,7 @@ where
         if !status.is_success() {
             let v = serde_json::from_slice(rsp.body())
                 .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
+            return Err(ApiError::from_response(self.endpoint.url_base(), status, v));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(header::LOCATION),
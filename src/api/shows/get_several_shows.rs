@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, ShowId, parse_ids},
+};
 
 /// Get Spotify catalog information for several shows based on their Spotify IDs.
 #[derive(Debug, Clone)]
 pub struct GetSeveralShows {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the shows.
-    pub ids: Vec<String>,
+    pub ids: Vec<ShowId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,19 +19,25 @@ pub struct GetSeveralShows {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for GetSeveralShows
+impl<I> From<I> for GetSeveralShows
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = ShowId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl GetSeveralShows {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<ShowId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralShows {
     fn method(&self) -> Method {
         Method::GET
@@ -40,7 +49,7 @@ impl Endpoint for GetSeveralShows {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
@@ -63,7 +72,9 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetSeveralShows::from(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"]);
+        let endpoint =
+            GetSeveralShows::from_ids(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
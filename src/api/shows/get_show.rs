@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, ShowId},
+};
 
 /// Get Spotify catalog information for a single show identified by its unique Spotify ID.
 #[derive(Debug, Clone)]
 pub struct GetShow {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the show.
-    pub id: String,
+    pub id: ShowId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,12 +19,25 @@ pub struct GetShow {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetShow {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<ShowId> for GetShow {
+    fn from(id: ShowId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetShow {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ShowId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetShow {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ShowId::from_id(id).map(Self::from)
     }
 }
 
@@ -31,7 +47,7 @@ impl Endpoint for GetShow {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("shows/{}", self.id).into()
+        format!("shows/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -57,7 +73,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetShow::from("38bS44xjbVVZ3No3ByF1dJ");
+        let endpoint = GetShow::try_from("38bS44xjbVVZ3No3ByF1dJ").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
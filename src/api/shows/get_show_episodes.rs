@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, ShowId},
+};
 
 /// Get Spotify catalog information about an show's episodes.
 #[derive(Debug, Clone)]
 pub struct GetShowEpisodes {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the show.
-    pub id: String,
+    pub id: ShowId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -18,12 +21,25 @@ pub struct GetShowEpisodes {
 
 impl Pageable for GetShowEpisodes {}
 
-impl<T: Into<String>> From<T> for GetShowEpisodes {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<ShowId> for GetShowEpisodes {
+    fn from(id: ShowId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetShowEpisodes {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        ShowId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetShowEpisodes {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        ShowId::from_id(id).map(Self::from)
     }
 }
 
@@ -33,7 +49,7 @@ impl Endpoint for GetShowEpisodes {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("shows/{}/episodes", self.id).into()
+        format!("shows/{}/episodes", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -59,7 +75,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetShowEpisodes::from("38bS44xjbVVZ3No3ByF1dJ");
+        let endpoint = GetShowEpisodes::try_from("38bS44xjbVVZ3No3ByF1dJ").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -14,6 +14,10 @@ impl Endpoint for GetUserSavedShows {
     fn endpoint(&self) -> Cow<'static, str> {
         "me/shows".into()
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,14 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, ShowId, parse_ids},
+};
 
 /// Remove one or more shows from the Spotify user's library.
 #[derive(Debug, Clone)]
 // #[endpoint(method = DELETE, path = "me/shows")]
 pub struct RemoveUserSavedShows {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the shows.
-    pub ids: Vec<String>,
+    pub ids: Vec<ShowId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -17,19 +20,25 @@ pub struct RemoveUserSavedShows {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for RemoveUserSavedShows
+impl<I> From<I> for RemoveUserSavedShows
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = ShowId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl RemoveUserSavedShows {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<ShowId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for RemoveUserSavedShows {
     fn method(&self) -> Method {
         Method::DELETE
@@ -41,10 +50,14 @@ impl Endpoint for RemoveUserSavedShows {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +80,8 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint =
-            RemoveUserSavedShows::from(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"]);
+            RemoveUserSavedShows::from_ids(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, ShowId, parse_ids},
+};
 
 /// Save one or more shows to the current Spotify user's library.
 #[derive(Debug, Clone)]
 pub struct SaveShowsforCurrentUser {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the shows.
-    pub ids: Vec<String>,
+    pub ids: Vec<ShowId>,
 }
 
-impl<T, I> From<I> for SaveShowsforCurrentUser
+impl<I> From<I> for SaveShowsforCurrentUser
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = ShowId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl SaveShowsforCurrentUser {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<ShowId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for SaveShowsforCurrentUser {
     fn method(&self) -> Method {
         Method::PUT
@@ -30,9 +39,13 @@ impl Endpoint for SaveShowsforCurrentUser {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -55,7 +68,8 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint =
-            SaveShowsforCurrentUser::from(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"]);
+            SaveShowsforCurrentUser::from_ids(["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -0,0 +1,16 @@
+//! Requests to the Spotify Accounts service that issue access tokens.
+//!
+//! These endpoints use [`UrlBase::Accounts`](crate::api::UrlBase::Accounts), so they are
+//! resolved with [`RestClient::accounts_endpoint`](crate::api::RestClient::accounts_endpoint)
+//! and sent through the same [`Client`](crate::api::Client) as every other request.
+//!
+//! The [`Spotify`](crate::Spotify) clients send them on their own; use them directly only when
+//! implementing a custom client.
+
+mod refresh_access_token;
+mod request_access_token;
+mod request_client_credentials_token;
+
+pub use refresh_access_token::*;
+pub use request_access_token::*;
+pub use request_client_credentials_token::*;
@@ -0,0 +1,128 @@
+use crate::api::prelude::*;
+
+/// Refresh an access token issued by the
+/// [Authorization Code with PKCE flow](https://developer.spotify.com/documentation/web-api/tutorials/refreshing-tokens).
+#[derive(Debug, Clone)]
+pub struct RefreshAccessToken {
+    /// The Client ID of your application.
+    pub client_id: String,
+
+    /// The refresh token returned with the access token.
+    pub refresh_token: Secret<String>,
+}
+
+impl RefreshAccessToken {
+    pub fn new(client_id: impl Into<String>, refresh_token: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            refresh_token: Secret::new(refresh_token.into()),
+        }
+    }
+}
+
+impl Endpoint for RefreshAccessToken {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "api/token".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Accounts
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params
+            .push("grant_type", &"refresh_token")
+            .push("refresh_token", self.refresh_token.expose_secret())
+            .push("client_id", &self.client_id);
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{ApiError, Query as _},
+        auth::AuthError,
+        model::Token,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_refresh_access_token_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=refresh_token&refresh_token=refresh&client_id=client_id")
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#,
+        );
+
+        let token: Token = RefreshAccessToken::new("client_id", "refresh")
+            .query(&client)
+            .unwrap();
+
+        assert!(token.refresh_token.is_none());
+    }
+
+    #[test]
+    fn test_refresh_access_token_unknown_error() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=refresh_token&refresh_token=refresh&client_id=client_id")
+            .status(http::StatusCode::BAD_REQUEST)
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"error": "server_error", "error_description": "Oops"}"#,
+        );
+
+        let err = RefreshAccessToken::new("client_id", "refresh")
+            .query(&client)
+            .map(|_: Token| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Auth(AuthError::OAuth { error, .. }) if error == "server_error"
+        ));
+    }
+
+    #[test]
+    fn test_refresh_access_token_web_api_error_format() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=refresh_token&refresh_token=refresh&client_id=client_id")
+            .status(http::StatusCode::UNAUTHORIZED)
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"error": {"status": 401, "message": "Invalid access token"}}"#,
+        );
+
+        let err = RefreshAccessToken::new("client_id", "refresh")
+            .query(&client)
+            .map(|_: Token| ())
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::SpotifyObjectWithStatus { .. }));
+    }
+}
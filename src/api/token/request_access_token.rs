@@ -0,0 +1,125 @@
+use crate::api::prelude::*;
+
+/// Exchange an authorization code for an access token, as part of the
+/// [Authorization Code with PKCE flow](https://developer.spotify.com/documentation/web-api/tutorials/code-pkce-flow).
+#[derive(Debug, Clone)]
+pub struct RequestAccessToken {
+    /// The Client ID of your application.
+    pub client_id: String,
+
+    /// The authorization code returned in the redirect URI.
+    pub code: String,
+
+    /// The redirect URI sent in the authorization URL.
+    pub redirect_uri: String,
+
+    /// The code verifier matching the `code_challenge` sent in the authorization URL.
+    pub code_verifier: Secret<String>,
+}
+
+impl RequestAccessToken {
+    pub fn new(
+        client_id: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        code_verifier: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            code: code.into(),
+            redirect_uri: redirect_uri.into(),
+            code_verifier: Secret::new(code_verifier.into()),
+        }
+    }
+}
+
+impl Endpoint for RequestAccessToken {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "api/token".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Accounts
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params
+            .push("grant_type", &"authorization_code")
+            .push("code", &self.code)
+            .push("redirect_uri", &self.redirect_uri)
+            .push("client_id", &self.client_id)
+            .push("code_verifier", self.code_verifier.expose_secret());
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{ApiError, Query as _},
+        auth::AuthError,
+        model::Token,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_request_access_token_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=authorization_code&code=abc&redirect_uri=http%3A%2F%2F127.0.0.1%3A8888%2Fcallback&client_id=client_id&code_verifier=verifier")
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#,
+        );
+
+        let token: Token = RequestAccessToken::new(
+            "client_id",
+            "abc",
+            "http://127.0.0.1:8888/callback",
+            "verifier",
+        )
+        .query(&client)
+        .unwrap();
+
+        assert_eq!(token.access_token.expose_secret(), "token");
+    }
+
+    #[test]
+    fn test_request_access_token_invalid_grant() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=authorization_code&code=abc&redirect_uri=uri&client_id=id&code_verifier=verifier")
+            .status(http::StatusCode::BAD_REQUEST)
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"error": "invalid_grant", "error_description": "Invalid authorization code"}"#,
+        );
+
+        let err = RequestAccessToken::new("id", "abc", "uri", "verifier")
+            .query(&client)
+            .map(|_: Token| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Auth(AuthError::InvalidGrant { description: Some(description) })
+                if description == "Invalid authorization code"
+        ));
+    }
+}
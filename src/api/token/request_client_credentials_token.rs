@@ -0,0 +1,129 @@
+use crate::api::prelude::*;
+use base64::{Engine as _, engine::general_purpose};
+use http::{HeaderMap, HeaderValue, header};
+
+/// Request an access token with the
+/// [Client Credentials flow](https://developer.spotify.com/documentation/web-api/tutorials/client-credentials-flow).
+#[derive(Debug, Clone)]
+pub struct RequestClientCredentialsToken {
+    /// The Client ID of your application.
+    pub client_id: String,
+
+    /// The Client Secret of your application.
+    pub client_secret: Secret<String>,
+}
+
+impl RequestClientCredentialsToken {
+    pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret.into()),
+        }
+    }
+}
+
+impl Endpoint for RequestClientCredentialsToken {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "api/token".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Accounts
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("grant_type", &"client_credentials");
+        params.into_body()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let credentials = Secret::new(format!(
+            "{}:{}",
+            self.client_id,
+            self.client_secret.expose_secret()
+        ));
+        let value = Secret::new(format!(
+            "Basic {}",
+            general_purpose::URL_SAFE_NO_PAD.encode(credentials.expose_secret())
+        ));
+
+        let mut headers = HeaderMap::new();
+
+        // Base64 output only contains visible ASCII characters, so this never fails.
+        if let Ok(mut value) = HeaderValue::from_str(value.expose_secret()) {
+            value.set_sensitive(true);
+            headers.insert(header::AUTHORIZATION, value);
+        }
+
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{ApiError, Query as _},
+        auth::AuthError,
+        model::Token,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_request_client_credentials_token_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=client_credentials")
+            .build();
+
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#,
+        );
+
+        let _: Token = RequestClientCredentialsToken::new("id", "secret")
+            .query(&client)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_request_client_credentials_token_headers() {
+        let headers = RequestClientCredentialsToken::new("id", "secret").headers();
+        let value = headers.get(header::AUTHORIZATION).unwrap();
+
+        assert_eq!(value, "Basic aWQ6c2VjcmV0");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    fn test_request_client_credentials_token_invalid_client() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=client_credentials")
+            .status(http::StatusCode::BAD_REQUEST)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, r#"{"error": "invalid_client"}"#);
+
+        let err = RequestClientCredentialsToken::new("id", "secret")
+            .query(&client)
+            .map(|_: Token| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Auth(AuthError::InvalidClient { description: None })
+        ));
+    }
+}
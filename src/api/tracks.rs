@@ -1,13 +1,21 @@
 mod check_user_saved_tracks;
+mod get_recommendations;
 mod get_several_tracks;
+mod get_several_tracks_audio_features;
 mod get_track;
+mod get_track_audio_analysis;
+mod get_track_audio_features;
 mod get_user_saved_tracks;
 mod remove_user_saved_tracks;
 mod save_tracks_for_current_user;
 
 pub use check_user_saved_tracks::*;
+pub use get_recommendations::*;
 pub use get_several_tracks::*;
+pub use get_several_tracks_audio_features::*;
 pub use get_track::*;
+pub use get_track_audio_analysis::*;
+pub use get_track_audio_features::*;
 pub use get_user_saved_tracks::*;
 pub use remove_user_saved_tracks::*;
 pub use save_tracks_for_current_user::*;
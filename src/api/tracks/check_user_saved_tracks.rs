@@ -1,24 +1,33 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, TrackId, parse_ids},
+};
 
 /// Check if one or more tracks are already saved in the current Spotify user's library.
 #[derive(Debug, Clone)]
 pub struct CheckUserSavedTracks {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
-    pub ids: Vec<String>,
+    pub ids: Vec<TrackId>,
 }
 
-impl<T, I> From<I> for CheckUserSavedTracks
+impl<I> From<I> for CheckUserSavedTracks
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = TrackId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl CheckUserSavedTracks {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<TrackId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for CheckUserSavedTracks {
     fn method(&self) -> Method {
         Method::GET
@@ -30,9 +39,13 @@ impl Endpoint for CheckUserSavedTracks {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -55,7 +68,8 @@ mod tests {
         let client = SingleTestClient::new_json(endpoint, &expected_response);
 
         let endpoint =
-            CheckUserSavedTracks::from(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"]);
+            CheckUserSavedTracks::from_ids(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"])
+                .unwrap();
 
         let result: Vec<bool> = endpoint.query(&client).unwrap();
 
@@ -0,0 +1,393 @@
+use crate::{
+    api::prelude::*,
+    model::{ArtistId, Key, Mode, TrackId},
+};
+use thiserror::Error;
+
+/// The maximum number of seeds Spotify accepts, across artists, genres and tracks.
+pub const MAX_SEEDS: usize = 5;
+
+/// Errors which may occur when creating a [`GetRecommendations`] endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum SeedError {
+    /// No seed artist, genre or track was given.
+    #[error("at least one seed artist, genre or track is required")]
+    Empty,
+
+    /// More than [`MAX_SEEDS`] seeds were given.
+    #[error("too many seeds: got {got}, the maximum is {MAX_SEEDS}")]
+    TooMany { got: usize },
+}
+
+/// A seed to generate recommendations from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Seed {
+    /// A [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of an artist.
+    Artist(ArtistId),
+
+    /// A genre from the [available genre seeds](crate::api::genres::GetAvailableGenreSeeds).
+    Genre(String),
+
+    /// A [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of a track.
+    Track(TrackId),
+}
+
+impl From<ArtistId> for Seed {
+    fn from(id: ArtistId) -> Self {
+        Self::Artist(id)
+    }
+}
+
+impl From<TrackId> for Seed {
+    fn from(id: TrackId) -> Self {
+        Self::Track(id)
+    }
+}
+
+/// A tunable track attribute, used as a minimum, maximum or target value of [`GetRecommendations`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TunableAttribute {
+    /// A confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    Acousticness(f64),
+
+    /// How suitable a track is for dancing, from 0.0 to 1.0.
+    Danceability(f64),
+
+    /// The duration of the track in milliseconds.
+    DurationMs(u32),
+
+    /// A perceptual measure of intensity and activity, from 0.0 to 1.0.
+    Energy(f64),
+
+    /// Whether a track contains no vocals, from 0.0 to 1.0.
+    Instrumentalness(f64),
+
+    /// The key the track is in.
+    Key(Key),
+
+    /// The presence of an audience in the recording, from 0.0 to 1.0.
+    Liveness(f64),
+
+    /// The overall loudness of a track in decibels (dB).
+    Loudness(f64),
+
+    /// The modality (major or minor) of a track.
+    Mode(Mode),
+
+    /// The popularity of the track, from 0 to 100.
+    Popularity(u8),
+
+    /// The presence of spoken words in a track, from 0.0 to 1.0.
+    Speechiness(f64),
+
+    /// The overall estimated tempo of a track in beats per minute (BPM).
+    Tempo(f64),
+
+    /// An estimated time signature, from 3 to 7.
+    TimeSignature(u8),
+
+    /// The musical positiveness conveyed by a track, from 0.0 to 1.0.
+    Valence(f64),
+}
+
+impl TunableAttribute {
+    /// The name of the attribute, as used in the `min_`, `max_` and `target_` parameters.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Acousticness(_) => "acousticness",
+            Self::Danceability(_) => "danceability",
+            Self::DurationMs(_) => "duration_ms",
+            Self::Energy(_) => "energy",
+            Self::Instrumentalness(_) => "instrumentalness",
+            Self::Key(_) => "key",
+            Self::Liveness(_) => "liveness",
+            Self::Loudness(_) => "loudness",
+            Self::Mode(_) => "mode",
+            Self::Popularity(_) => "popularity",
+            Self::Speechiness(_) => "speechiness",
+            Self::Tempo(_) => "tempo",
+            Self::TimeSignature(_) => "time_signature",
+            Self::Valence(_) => "valence",
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Acousticness(value)
+            | Self::Danceability(value)
+            | Self::Energy(value)
+            | Self::Instrumentalness(value)
+            | Self::Liveness(value)
+            | Self::Loudness(value)
+            | Self::Speechiness(value)
+            | Self::Tempo(value)
+            | Self::Valence(value) => value.to_string(),
+            Self::DurationMs(value) => value.to_string(),
+            Self::Key(key) => key.pitch_class().to_string(),
+            Self::Mode(mode) => mode.value().to_string(),
+            Self::Popularity(value) | Self::TimeSignature(value) => value.to_string(),
+        }
+    }
+}
+
+/// Recommendations are generated based on the available information for a given seed entity and matched against similar artists and tracks.
+/// If there is sufficient information about the provided seeds, a list of tracks will be returned together with pool size details.
+///
+/// # Example
+/// ```no_run
+/// use spotify_web_api::{
+///     api::{Query as _, tracks::{GetRecommendations, Seed, TunableAttribute}},
+///     model::{ArtistId, Recommendations},
+///     Spotify,
+/// };
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let spotify = Spotify::with_client_credentials("client_id", "client_secret")?;
+///
+/// let recommendations: Recommendations = GetRecommendations::new([
+///     Seed::Artist(ArtistId::from_id("4NHQUGzhtTLFvgF5SZesLK")?),
+///     Seed::Genre("classical".to_owned()),
+/// ])?
+/// .min(TunableAttribute::Energy(0.6))
+/// .target(TunableAttribute::Tempo(128.0))
+/// .limit(50)
+/// .query(&spotify)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GetRecommendations {
+    seeds: Vec<Seed>,
+
+    /// The target size of the list of recommended tracks, from 1 to 100. Defaults to 20.
+    pub limit: Option<u8>,
+
+    /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
+    /// If a country code is specified, only content that is available in that market will be returned.
+    /// If a valid user access token is specified in the request header, the country associated with the user account will take priority over this parameter.
+    pub market: Option<Market>,
+
+    /// Hard floors on the tunable attributes of the recommended tracks.
+    pub min: Vec<TunableAttribute>,
+
+    /// Hard ceilings on the tunable attributes of the recommended tracks.
+    pub max: Vec<TunableAttribute>,
+
+    /// Target values of the tunable attributes. Tracks with values nearest to the targets are preferred.
+    pub target: Vec<TunableAttribute>,
+}
+
+impl GetRecommendations {
+    /// The maximum number of recommended tracks Spotify returns.
+    pub const MAX_LIMIT: u8 = 100;
+
+    /// Creates the endpoint from up to [`MAX_SEEDS`] seed artists, genres and tracks combined.
+    pub fn new(seeds: impl IntoIterator<Item = Seed>) -> Result<Self, SeedError> {
+        let seeds: Vec<_> = seeds.into_iter().collect();
+
+        match seeds.len() {
+            0 => return Err(SeedError::Empty),
+            got if got > MAX_SEEDS => return Err(SeedError::TooMany { got }),
+            _ => {}
+        }
+
+        Ok(Self {
+            seeds,
+            limit: None,
+            market: None,
+            min: Vec::new(),
+            max: Vec::new(),
+            target: Vec::new(),
+        })
+    }
+
+    /// The seeds the recommendations are generated from.
+    pub fn seeds(&self) -> &[Seed] {
+        &self.seeds
+    }
+
+    /// Sets the target size of the list of recommended tracks, clamped to 1..=100.
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit.clamp(1, Self::MAX_LIMIT));
+        self
+    }
+
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    /// Sets a hard floor on an attribute, replacing any previous floor for it.
+    pub fn min(mut self, attribute: TunableAttribute) -> Self {
+        set_attribute(&mut self.min, attribute);
+        self
+    }
+
+    /// Sets a hard ceiling on an attribute, replacing any previous ceiling for it.
+    pub fn max(mut self, attribute: TunableAttribute) -> Self {
+        set_attribute(&mut self.max, attribute);
+        self
+    }
+
+    /// Sets a target value for an attribute, replacing any previous target for it.
+    pub fn target(mut self, attribute: TunableAttribute) -> Self {
+        set_attribute(&mut self.target, attribute);
+        self
+    }
+
+    fn seed_ids(&self, select: impl Fn(&Seed) -> Option<&str>) -> Option<String> {
+        let ids: Vec<_> = self.seeds.iter().filter_map(select).collect();
+        (!ids.is_empty()).then(|| ids.join(","))
+    }
+}
+
+fn set_attribute(attributes: &mut Vec<TunableAttribute>, attribute: TunableAttribute) {
+    attributes.retain(|existing| existing.name() != attribute.name());
+    attributes.push(attribute);
+}
+
+impl Endpoint for GetRecommendations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "recommendations".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push_opt(
+            "seed_artists",
+            self.seed_ids(|seed| match seed {
+                Seed::Artist(id) => Some(id.id()),
+                _ => None,
+            }),
+        );
+        params.push_opt(
+            "seed_genres",
+            self.seed_ids(|seed| match seed {
+                Seed::Genre(genre) => Some(genre.as_str()),
+                _ => None,
+            }),
+        );
+        params.push_opt(
+            "seed_tracks",
+            self.seed_ids(|seed| match seed {
+                Seed::Track(id) => Some(id.id()),
+                _ => None,
+            }),
+        );
+        params.push_opt(
+            "limit",
+            self.limit.map(|limit| limit.clamp(1, Self::MAX_LIMIT)),
+        );
+        params.push_opt("market", self.market.as_ref());
+
+        for (prefix, attributes) in [
+            ("min", &self.min),
+            ("max", &self.max),
+            ("target", &self.target),
+        ] {
+            for attribute in attributes {
+                params.push(format!("{prefix}_{}", attribute.name()), &attribute.value());
+            }
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::{RecommendationSeedType, Recommendations},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_recommendations_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("recommendations")
+            .add_query_params(&[
+                ("seed_artists", "4NHQUGzhtTLFvgF5SZesLK"),
+                ("seed_genres", "classical,country"),
+                ("seed_tracks", "0c6xIDDpzE81m2q797ordA"),
+                ("limit", "50"),
+                ("min_energy", "0.4"),
+                ("max_popularity", "80"),
+                ("target_key", "9"),
+                ("target_tempo", "128"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetRecommendations::new([
+            Seed::Artist(ArtistId::from_id("4NHQUGzhtTLFvgF5SZesLK").unwrap()),
+            Seed::Genre("classical".to_owned()),
+            Seed::Genre("country".to_owned()),
+            Seed::Track(TrackId::from_id("0c6xIDDpzE81m2q797ordA").unwrap()),
+        ])
+        .unwrap()
+        .limit(50)
+        .min(TunableAttribute::Energy(0.4))
+        .max(TunableAttribute::Popularity(80))
+        .target(TunableAttribute::Tempo(120.0))
+        .target(TunableAttribute::Key(Key::A))
+        .target(TunableAttribute::Tempo(128.0));
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_recommendations_response() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("recommendations")
+            .add_query_params(&[("seed_genres", "classical")])
+            .build();
+
+        let json = r#"{
+            "seeds": [
+                {
+                    "afterFilteringSize": 250,
+                    "afterRelinkingSize": 250,
+                    "href": null,
+                    "id": "classical",
+                    "initialPoolSize": 250,
+                    "type": "GENRE"
+                }
+            ],
+            "tracks": []
+        }"#;
+
+        let client = SingleTestClient::new_raw(endpoint, json);
+
+        let endpoint = GetRecommendations::new([Seed::Genre("classical".to_owned())]).unwrap();
+
+        let recommendations: Recommendations = endpoint.query(&client).unwrap();
+
+        assert_eq!(recommendations.seeds.len(), 1);
+        assert_eq!(recommendations.seeds[0].id, "classical");
+        assert_eq!(
+            recommendations.seeds[0].type_,
+            RecommendationSeedType::Genre
+        );
+        assert!(recommendations.tracks.is_empty());
+    }
+
+    #[test]
+    fn test_get_recommendations_seed_validation() {
+        assert_eq!(GetRecommendations::new([]).unwrap_err(), SeedError::Empty);
+
+        let seeds = (0..6).map(|i| Seed::Genre(i.to_string()));
+        assert_eq!(
+            GetRecommendations::new(seeds).unwrap_err(),
+            SeedError::TooMany { got: 6 }
+        );
+    }
+}
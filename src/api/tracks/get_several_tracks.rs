@@ -1,11 +1,14 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, TrackId, parse_ids},
+};
 
 /// Get Spotify catalog information for several tracks based on their Spotify IDs.
 #[derive(Debug, Clone)]
 // #[endpoint(method = GET, path = "tracks")]
 pub struct GetSeveralTracks {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
-    pub ids: Vec<String>,
+    pub ids: Vec<TrackId>,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -17,19 +20,25 @@ pub struct GetSeveralTracks {
     pub market: Option<Market>,
 }
 
-impl<T, I> From<I> for GetSeveralTracks
+impl<I> From<I> for GetSeveralTracks
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = TrackId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
             market: None,
         }
     }
 }
 
+impl GetSeveralTracks {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<TrackId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for GetSeveralTracks {
     fn method(&self) -> Method {
         Method::GET
@@ -41,7 +50,7 @@ impl Endpoint for GetSeveralTracks {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params.push_opt("market", self.market.as_ref());
         params
     }
@@ -64,8 +73,19 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetSeveralTracks::from(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"]);
+        let endpoint =
+            GetSeveralTracks::from_ids(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_several_tracks_rejects_invalid_ids() {
+        assert_eq!(
+            GetSeveralTracks::from_ids(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW-"])
+                .unwrap_err(),
+            IdError::InvalidFormat
+        );
+    }
 }
@@ -0,0 +1,145 @@
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, TrackId, parse_ids},
+};
+
+/// Get audio features for multiple tracks based on their Spotify IDs.
+///
+/// Spotify accepts at most [`MAX_IDS`](Self::MAX_IDS) IDs per request, so creating the endpoint
+/// from more fails with [`IdError::TooManyIds`].
+/// Use [`chunks`](Self::chunks) to split a longer list into several requests.
+#[derive(Debug, Clone)]
+pub struct GetSeveralTracksAudioFeatures {
+    /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
+    ids: Vec<TrackId>,
+}
+
+impl GetSeveralTracksAudioFeatures {
+    /// The maximum number of IDs Spotify accepts in a single request.
+    pub const MAX_IDS: usize = 100;
+
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one or if there are
+    /// more than [`MAX_IDS`](Self::MAX_IDS).
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        Self::try_from(parse_ids::<TrackId, _>(ids)?)
+    }
+
+    /// Splits any number of IDs into requests of at most [`MAX_IDS`](Self::MAX_IDS) IDs each.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use spotify_web_api::{api::{Query as _, tracks::GetSeveralTracksAudioFeatures}, model::{AudioFeaturesList, TrackId}, Spotify};
+    /// # let spotify = Spotify::with_client_credentials("client_id", "client_secret").unwrap();
+    /// # let ids: Vec<TrackId> = Vec::new();
+    /// let mut features = Vec::new();
+    ///
+    /// for endpoint in GetSeveralTracksAudioFeatures::chunks(ids) {
+    ///     let list: AudioFeaturesList = endpoint.query(&spotify).unwrap();
+    ///     features.extend(list.audio_features);
+    /// }
+    /// ```
+    pub fn chunks(ids: impl IntoIterator<Item = TrackId>) -> impl Iterator<Item = Self> {
+        let mut ids = ids.into_iter().peekable();
+
+        std::iter::from_fn(move || {
+            ids.peek()?;
+
+            Some(Self {
+                ids: ids.by_ref().take(Self::MAX_IDS).collect(),
+            })
+        })
+    }
+
+    /// The IDs of the tracks.
+    pub fn ids(&self) -> &[TrackId] {
+        &self.ids
+    }
+}
+
+impl TryFrom<Vec<TrackId>> for GetSeveralTracksAudioFeatures {
+    type Error = IdError;
+
+    fn try_from(ids: Vec<TrackId>) -> Result<Self, Self::Error> {
+        if ids.len() > Self::MAX_IDS {
+            return Err(IdError::TooManyIds {
+                count: ids.len(),
+                max: Self::MAX_IDS,
+            });
+        }
+
+        Ok(Self { ids })
+    }
+}
+
+impl Endpoint for GetSeveralTracksAudioFeatures {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "audio-features".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("ids", &comma_separated_ids(&self.ids));
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    fn track_ids(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("{i:0>22}")).collect()
+    }
+
+    #[test]
+    fn test_get_several_tracks_audio_features_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-features")
+            .add_query_params(&[("ids", "7ouMYWpwJ422jRcDASZB7P,4VqPOruhp5EdPBeR92t6lQ")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralTracksAudioFeatures::from_ids([
+            "7ouMYWpwJ422jRcDASZB7P",
+            "4VqPOruhp5EdPBeR92t6lQ",
+        ])
+        .unwrap();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_several_tracks_audio_features_chunks() {
+        let ids = parse_ids::<TrackId, _>(track_ids(250)).unwrap();
+
+        let chunks: Vec<_> = GetSeveralTracksAudioFeatures::chunks(ids)
+            .map(|chunk| chunk.ids().len())
+            .collect();
+
+        assert_eq!(chunks, vec![100, 100, 50]);
+    }
+
+    #[test]
+    fn test_get_several_tracks_audio_features_too_many_ids() {
+        assert!(GetSeveralTracksAudioFeatures::from_ids(track_ids(100)).is_ok());
+
+        let err = GetSeveralTracksAudioFeatures::from_ids(track_ids(101)).unwrap_err();
+
+        assert_eq!(
+            err,
+            IdError::TooManyIds {
+                count: 101,
+                max: 100
+            }
+        );
+    }
+}
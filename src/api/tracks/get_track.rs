@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, TrackId},
+};
 
 /// Get Spotify catalog information for a single track identified by its unique Spotify ID.
 #[derive(Debug, Clone)]
 pub struct GetTrack {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the track.
-    pub id: String,
+    pub id: TrackId,
 
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -16,12 +19,25 @@ pub struct GetTrack {
     pub market: Option<Market>,
 }
 
-impl<T: Into<String>> From<T> for GetTrack {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            market: None,
-        }
+impl From<TrackId> for GetTrack {
+    fn from(id: TrackId) -> Self {
+        Self { id, market: None }
+    }
+}
+
+impl TryFrom<&str> for GetTrack {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetTrack {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
     }
 }
 
@@ -31,7 +47,7 @@ impl Endpoint for GetTrack {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("tracks/{}", self.id).into()
+        format!("tracks/{}", self.id.id()).into()
     }
 
     fn parameters(&self) -> QueryParams<'_> {
@@ -57,8 +73,26 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetTrack::from("5IoBP6aTHHBStOrbLFc5uQ");
+        let endpoint = GetTrack::try_from("5IoBP6aTHHBStOrbLFc5uQ").unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_track_rejects_invalid_id() {
+        assert_eq!(
+            GetTrack::try_from("not a track id").unwrap_err(),
+            IdError::InvalidLength {
+                got: 14,
+                expected: 22
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_track_from_string() {
+        let endpoint = GetTrack::try_from(String::from("5IoBP6aTHHBStOrbLFc5uQ")).unwrap();
+
+        assert_eq!(endpoint.id.id(), "5IoBP6aTHHBStOrbLFc5uQ");
+    }
 }
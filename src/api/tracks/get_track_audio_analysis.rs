@@ -0,0 +1,131 @@
+use crate::{
+    api::prelude::*,
+    model::{IdError, TrackId},
+};
+
+/// Get a low-level audio analysis for a track in the Spotify catalog.
+/// The audio analysis describes the track's structure and musical content, including rhythm, pitch, and timbre.
+#[derive(Debug, Clone)]
+pub struct GetTrackAudioAnalysis {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the track.
+    pub id: TrackId,
+}
+
+impl From<TrackId> for GetTrackAudioAnalysis {
+    fn from(id: TrackId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetTrackAudioAnalysis {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetTrackAudioAnalysis {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
+    }
+}
+
+impl Endpoint for GetTrackAudioAnalysis {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("audio-analysis/{}", self.id.id()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::{AudioAnalysis, Key, Mode},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_track_audio_analysis_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-analysis/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetTrackAudioAnalysis::try_from("11dFghVXANMlKmJXsNCbNl").unwrap();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_track_audio_analysis_response() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-analysis/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let json = r#"{
+            "meta": {
+                "analyzer_version": "4.0.0",
+                "platform": "Linux",
+                "detailed_status": "OK",
+                "status_code": 0,
+                "timestamp": 1495193577,
+                "analysis_time": 6.93906,
+                "input_process": "libvorbisfile L+R 44100->22050"
+            },
+            "track": {
+                "num_samples": 4585515,
+                "duration": 207.95985,
+                "sample_md5": "",
+                "offset_seconds": 0,
+                "window_seconds": 0,
+                "analysis_sample_rate": 22050,
+                "analysis_channels": 1,
+                "end_of_fade_in": 0,
+                "start_of_fade_out": 201.13705,
+                "loudness": -5.883,
+                "tempo": 118.211,
+                "tempo_confidence": 0.73,
+                "time_signature": 4,
+                "time_signature_confidence": 0.994,
+                "key": 9,
+                "key_confidence": 0.408,
+                "mode": 1,
+                "mode_confidence": 0.485,
+                "codestring": "",
+                "code_version": 3.15,
+                "echoprintstring": "",
+                "echoprint_version": 4.15,
+                "synchstring": "",
+                "synch_version": 1,
+                "rhythmstring": "",
+                "rhythm_version": 1
+            },
+            "bars": [],
+            "beats": [{ "start": 0.49567, "duration": 2.18749, "confidence": 0.925 }],
+            "sections": [],
+            "segments": [],
+            "tatums": []
+        }"#;
+
+        let client = SingleTestClient::new_raw(endpoint, json);
+
+        let endpoint = GetTrackAudioAnalysis::try_from("11dFghVXANMlKmJXsNCbNl").unwrap();
+
+        let analysis: AudioAnalysis = endpoint.query(&client).unwrap();
+
+        assert_eq!(analysis.meta.status_code, 0);
+        assert_eq!(analysis.track.key, Some(Key::A));
+        assert_eq!(analysis.track.mode, Some(Mode::Major));
+        assert_eq!(analysis.beats.len(), 1);
+        assert!(analysis.sections.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    api::prelude::*,
+    model::{IdError, TrackId},
+};
+
+/// Get audio feature information for a single track identified by its unique Spotify ID.
+#[derive(Debug, Clone)]
+pub struct GetTrackAudioFeatures {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the track.
+    pub id: TrackId,
+}
+
+impl From<TrackId> for GetTrackAudioFeatures {
+    fn from(id: TrackId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetTrackAudioFeatures {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetTrackAudioFeatures {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        TrackId::from_id(id).map(Self::from)
+    }
+}
+
+impl Endpoint for GetTrackAudioFeatures {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("audio-features/{}", self.id.id()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::{AudioFeatures, Key, Mode},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_track_audio_features_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-features/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetTrackAudioFeatures::try_from("11dFghVXANMlKmJXsNCbNl").unwrap();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_track_audio_features_response() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-features/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let json = r#"{
+            "acousticness": 0.00242,
+            "analysis_url": "https://api.spotify.com/v1/audio-analysis/11dFghVXANMlKmJXsNCbNl",
+            "danceability": 0.585,
+            "duration_ms": 237040,
+            "energy": 0.842,
+            "id": "11dFghVXANMlKmJXsNCbNl",
+            "instrumentalness": 0.00686,
+            "key": 9,
+            "liveness": 0.0866,
+            "loudness": -5.883,
+            "mode": 0,
+            "speechiness": 0.0556,
+            "tempo": 118.211,
+            "time_signature": 4,
+            "track_href": "https://api.spotify.com/v1/tracks/11dFghVXANMlKmJXsNCbNl",
+            "type": "audio_features",
+            "uri": "spotify:track:11dFghVXANMlKmJXsNCbNl",
+            "valence": 0.428
+        }"#;
+
+        let client = SingleTestClient::new_raw(endpoint, json);
+
+        let endpoint = GetTrackAudioFeatures::try_from("11dFghVXANMlKmJXsNCbNl").unwrap();
+
+        let features: AudioFeatures = endpoint.query(&client).unwrap();
+
+        assert_eq!(features.id, "11dFghVXANMlKmJXsNCbNl");
+        assert_eq!(features.key, Some(Key::A));
+        assert_eq!(features.mode, Some(Mode::Minor));
+        assert_eq!(features.duration_ms, 237_040);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, TrackId, parse_ids},
+};
 
 /// Remove one or more tracks from the current user's library.
 ///
@@ -6,21 +9,27 @@ use crate::api::prelude::*;
 #[derive(Debug, Clone)]
 pub struct RemoveUserSavedTracks {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
-    pub ids: Vec<String>,
+    pub ids: Vec<TrackId>,
 }
 
-impl<T, I> From<I> for RemoveUserSavedTracks
+impl<I> From<I> for RemoveUserSavedTracks
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = TrackId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl RemoveUserSavedTracks {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<TrackId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for RemoveUserSavedTracks {
     fn method(&self) -> Method {
         Method::DELETE
@@ -32,9 +41,13 @@ impl Endpoint for RemoveUserSavedTracks {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +70,8 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint =
-            RemoveUserSavedTracks::from(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"]);
+            RemoveUserSavedTracks::from_ids(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"])
+                .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,4 +1,7 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{common::comma_separated_ids, prelude::*},
+    model::{IdError, TrackId, parse_ids},
+};
 
 /// Save one or more tracks to the current user's library.
 ///
@@ -6,21 +9,27 @@ use crate::api::prelude::*;
 #[derive(Debug, Clone)]
 pub struct SaveTracksforCurrentUser {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
-    pub ids: Vec<String>,
+    pub ids: Vec<TrackId>,
 }
 
-impl<T, I> From<I> for SaveTracksforCurrentUser
+impl<I> From<I> for SaveTracksforCurrentUser
 where
-    I: IntoIterator<Item = T>,
-    T: Into<String>,
+    I: IntoIterator<Item = TrackId>,
 {
     fn from(ids: I) -> Self {
         Self {
-            ids: ids.into_iter().map(Into::into).collect(),
+            ids: ids.into_iter().collect(),
         }
     }
 }
 
+impl SaveTracksforCurrentUser {
+    /// Creates the endpoint from Spotify IDs, failing on the first invalid one.
+    pub fn from_ids<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Result<Self, IdError> {
+        parse_ids::<TrackId, _>(ids).map(Self::from)
+    }
+}
+
 impl Endpoint for SaveTracksforCurrentUser {
     fn method(&self) -> Method {
         Method::PUT
@@ -32,9 +41,13 @@ impl Endpoint for SaveTracksforCurrentUser {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
+        params.push("ids", &comma_separated_ids(&self.ids));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserLibraryModify.into()
+    }
 }
 
 #[cfg(test)]
@@ -56,8 +69,11 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint =
-            SaveTracksforCurrentUser::from(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"]);
+        let endpoint = SaveTracksforCurrentUser::from_ids([
+            "39joRyXYyjSpI6nKZHyWmH",
+            "5mPY98zmeNSp8cmrRtdUW3",
+        ])
+        .unwrap();
 
         api::ignore(endpoint).query(&client).unwrap();
     }
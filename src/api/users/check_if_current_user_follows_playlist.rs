@@ -1,21 +1,40 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, PlaylistId},
+};
 
 /// Check to see if the current user is following a specified playlist.
 #[derive(Debug, Clone)]
 pub struct CheckIfCurrentUserFollowsPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 }
 
 impl CheckIfCurrentUserFollowsPlaylist {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: PlaylistId) -> Self {
         Self::from(id)
     }
 }
 
-impl<T: Into<String>> From<T> for CheckIfCurrentUserFollowsPlaylist {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<PlaylistId> for CheckIfCurrentUserFollowsPlaylist {
+    fn from(id: PlaylistId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for CheckIfCurrentUserFollowsPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for CheckIfCurrentUserFollowsPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
     }
 }
 
@@ -25,7 +44,7 @@ impl Endpoint for CheckIfCurrentUserFollowsPlaylist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/followers/contains", self.id).into()
+        format!("playlists/{}/followers/contains", self.id.id()).into()
     }
 }
 
@@ -47,7 +66,9 @@ mod tests {
 
         let client = SingleTestClient::new_json(endpoint, &expected_response);
 
-        let endpoint = CheckIfCurrentUserFollowsPlaylist::new("3cEYpjA9oz9GiPac4AsH4n");
+        let endpoint = CheckIfCurrentUserFollowsPlaylist::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+        );
 
         let result: Vec<bool> = endpoint.query(&client).unwrap();
 
@@ -26,6 +26,10 @@ impl Endpoint for CheckIfUserFollowsArtistsOrUsers {
         params.push("ids", &self.ids.join(","));
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserFollowRead.into()
+    }
 }
 
 #[cfg(test)]
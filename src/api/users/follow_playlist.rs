@@ -1,10 +1,13 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, PlaylistId},
+};
 
 /// Add the current user as a follower of a playlist.
 #[derive(Debug, Clone)]
 pub struct FollowPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 
     /// Defaults to true.
     /// If true the playlist will be included in user's public playlists (added to profile),
@@ -14,20 +17,30 @@ pub struct FollowPlaylist {
 }
 
 impl FollowPlaylist {
-    pub fn new(id: impl Into<String>, public: bool) -> Self {
-        Self {
-            id: id.into(),
-            public,
-        }
+    pub fn new(id: PlaylistId, public: bool) -> Self {
+        Self { id, public }
     }
 }
 
-impl<T: Into<String>> From<T> for FollowPlaylist {
-    fn from(id: T) -> Self {
-        Self {
-            id: id.into(),
-            public: true,
-        }
+impl From<PlaylistId> for FollowPlaylist {
+    fn from(id: PlaylistId) -> Self {
+        Self { id, public: true }
+    }
+}
+
+impl TryFrom<&str> for FollowPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for FollowPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
     }
 }
 
@@ -37,7 +50,7 @@ impl Endpoint for FollowPlaylist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/followers", self.id).into()
+        format!("playlists/{}/followers", self.id.id()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -45,6 +58,10 @@ impl Endpoint for FollowPlaylist {
             "public": self.public,
         }))
     }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
+    }
 }
 
 #[cfg(test)]
@@ -66,7 +83,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        api::ignore(FollowPlaylist::from("3cEYpjA9oz9GiPac4AsH4n"))
+        api::ignore(FollowPlaylist::try_from("3cEYpjA9oz9GiPac4AsH4n").unwrap())
             .query(&client)
             .unwrap();
     }
@@ -82,8 +99,11 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        api::ignore(FollowPlaylist::new("3cEYpjA9oz9GiPac4AsH4n", false))
-            .query(&client)
-            .unwrap();
+        api::ignore(FollowPlaylist::new(
+            PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            false,
+        ))
+        .query(&client)
+        .unwrap();
     }
 }
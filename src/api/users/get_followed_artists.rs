@@ -49,6 +49,10 @@ impl Endpoint for GetFollowedArtists {
         params.push_opt("after", self.after.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserFollowRead.into()
+    }
 }
 
 #[cfg(test)]
@@ -1,21 +1,40 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, UserId},
+};
 
 /// Get public profile information about a Spotify user.
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct GetUserProfile {
     /// The user's [Spotify user ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids).
-    pub id: String,
+    pub id: UserId,
 }
 
 impl GetUserProfile {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: UserId) -> Self {
         Self::from(id)
     }
 }
 
-impl<T: Into<String>> From<T> for GetUserProfile {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<UserId> for GetUserProfile {
+    fn from(id: UserId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for GetUserProfile {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        UserId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for GetUserProfile {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        UserId::from_id(id).map(Self::from)
     }
 }
 
@@ -25,7 +44,7 @@ impl Endpoint for GetUserProfile {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}", self.id).into()
+        format!("users/{}", self.id.id()).into()
     }
 }
 
@@ -41,7 +60,7 @@ mod tests {
     fn test_get_user_profile_endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("users/severino246").build();
         let client = SingleTestClient::new_raw(endpoint, "");
-        api::ignore(GetUserProfile::from("severino246"))
+        api::ignore(GetUserProfile::try_from("severino246").unwrap())
             .query(&client)
             .unwrap();
     }
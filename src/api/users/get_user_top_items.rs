@@ -46,6 +46,10 @@ impl Endpoint for GetUserTopItems {
         params.push_opt("time_range", self.time_range.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserTopRead.into()
+    }
 }
 
 #[cfg(test)]
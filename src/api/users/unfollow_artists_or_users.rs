@@ -31,6 +31,10 @@ impl Endpoint for UnfollowArtistsOrUsers {
             "ids": self.ids,
         }))
     }
+
+    fn required_scopes(&self) -> HashSet<Scope> {
+        Scope::UserFollowModify.into()
+    }
 }
 
 #[cfg(test)]
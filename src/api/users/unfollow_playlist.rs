@@ -1,21 +1,40 @@
-use crate::api::prelude::*;
+use crate::{
+    api::prelude::*,
+    model::{IdError, PlaylistId},
+};
 
 /// Remove the current user as a follower of a playlist.
 #[derive(Debug, Clone)]
 pub struct UnfollowPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
-    pub id: String,
+    pub id: PlaylistId,
 }
 
 impl UnfollowPlaylist {
-    pub fn new(id: impl Into<String>) -> Self {
+    pub fn new(id: PlaylistId) -> Self {
         Self::from(id)
     }
 }
 
-impl<T: Into<String>> From<T> for UnfollowPlaylist {
-    fn from(id: T) -> Self {
-        Self { id: id.into() }
+impl From<PlaylistId> for UnfollowPlaylist {
+    fn from(id: PlaylistId) -> Self {
+        Self { id }
+    }
+}
+
+impl TryFrom<&str> for UnfollowPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
+    }
+}
+
+impl TryFrom<String> for UnfollowPlaylist {
+    type Error = IdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlaylistId::from_id(id).map(Self::from)
     }
 }
 
@@ -25,7 +44,11 @@ impl Endpoint for UnfollowPlaylist {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("playlists/{}/followers", self.id).into()
+        format!("playlists/{}/followers", self.id.id()).into()
+    }
+
+    fn any_of_scopes(&self) -> HashSet<Scope> {
+        scopes::playlist_modify()
     }
 }
 
@@ -46,7 +69,7 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        api::ignore(UnfollowPlaylist::from("3cEYpjA9oz9GiPac4AsH4n"))
+        api::ignore(UnfollowPlaylist::try_from("3cEYpjA9oz9GiPac4AsH4n").unwrap())
             .query(&client)
             .unwrap();
     }
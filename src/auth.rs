@@ -1,21 +1,19 @@
 //! Types used in the authorization processs.
 
+#[cfg(feature = "callback_server")]
+pub mod callback_server;
 mod client_credentials;
 pub mod pkce;
 pub mod scopes;
+mod secret;
 
-use crate::{
-    RestError,
-    api::{ApiError, FormParams, query},
-    model::Token,
-};
-use bytes::Bytes;
 pub(crate) use client_credentials::ClientCredentials;
-use http::{HeaderMap, HeaderValue, Request, Response as HttpResponse, header, request::Builder};
+use http::header;
 pub(crate) use pkce::AuthCodePKCE;
-use reqwest::blocking::Client;
+use scopes::Scope;
+pub use secret::Secret;
+use std::collections::HashSet;
 use thiserror::Error;
-use url::Url;
 
 pub type AuthResult<T> = Result<T, AuthError>;
 
@@ -94,169 +92,230 @@ pub enum AuthError {
     /// token refreshing cannot proceed.
     #[error("refresh token is empty")]
     EmptyRefreshToken,
+
+    /// Indicates that the user denied the authorization request.
+    ///
+    /// This error occurs when the user clicks "Cancel" on the authorization page and Spotify
+    /// redirects back with `error=access_denied`.
+    #[error("the user denied the authorization request")]
+    AuthorizationDenied {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// The request is missing a required parameter, includes an unsupported parameter value,
+    /// or is otherwise malformed (`invalid_request`).
+    #[error("invalid request: {}", description.as_deref().unwrap_or("<none>"))]
+    InvalidRequest {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// Client authentication failed, for example because of an unknown client ID or a wrong
+    /// client secret (`invalid_client`).
+    #[error("invalid client: {}", description.as_deref().unwrap_or("<none>"))]
+    InvalidClient {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// The authorization code or refresh token is invalid, expired, revoked, or was issued to
+    /// another client (`invalid_grant`).
+    #[error("invalid grant: {}", description.as_deref().unwrap_or("<none>"))]
+    InvalidGrant {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// The client is not allowed to use this authorization grant type (`unauthorized_client`).
+    #[error("unauthorized client: {}", description.as_deref().unwrap_or("<none>"))]
+    UnauthorizedClient {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// The authorization grant type is not supported (`unsupported_grant_type`).
+    #[error("unsupported grant type: {}", description.as_deref().unwrap_or("<none>"))]
+    UnsupportedGrantType {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// The requested scope is invalid or unknown (`invalid_scope`).
+    #[error("invalid scope: {}", description.as_deref().unwrap_or("<none>"))]
+    InvalidScope {
+        /// The human-readable description sent by Spotify, if any.
+        description: Option<String>,
+    },
+
+    /// Any other OAuth error returned by the Spotify Accounts service.
+    ///
+    /// # Fields
+    /// - `error`: The OAuth error code.
+    /// - `description`: The human-readable description sent by Spotify, if any.
+    #[error("oauth error {error}: {}", description.as_deref().unwrap_or("<none>"))]
+    OAuth {
+        error: String,
+        description: Option<String>,
+    },
+
+    /// Indicates that the access token was not granted the scopes an endpoint requires.
+    ///
+    /// This error is only returned by clients with scope checking enabled, before the request
+    /// is sent to Spotify.
+    ///
+    /// # Fields
+    /// - `required`: The scopes required by the endpoint, including the ones of which only one
+    ///   is needed, such as `playlist-modify-public` and `playlist-modify-private`.
+    /// - `granted`: The scopes granted to the access token.
+    #[error(
+        "missing scopes: required [{}], granted [{}]",
+        scopes::to_string(required),
+        scopes::to_string(granted)
+    )]
+    MissingScopes {
+        required: HashSet<Scope>,
+        granted: HashSet<Scope>,
+    },
+
+    /// Indicates that a restored [`pkce::PendingAuthorization`] has expired.
+    ///
+    /// The user must go through the authorization process again by generating a new
+    /// authorization URL.
+    #[error("the pending authorization has expired")]
+    PendingAuthorizationExpired,
+
+    /// An I/O error occurred while running the local callback server.
+    ///
+    /// # Details
+    /// This variant wraps a `std::io::Error`, which occurs when binding to the redirect URI's
+    /// address fails (for example, because the port is already in use) or when accepting
+    /// a connection fails.
+    #[cfg(feature = "callback_server")]
+    #[error("callback server error: {0}")]
+    CallbackServer(#[from] std::io::Error),
+
+    /// Indicates that the local callback server did not receive the redirect in time.
+    #[cfg(feature = "callback_server")]
+    #[error("timed out waiting for the authorization callback")]
+    CallbackTimeout,
+
+    /// Indicates that the host of the redirect URI is not a loopback address, so the local
+    /// callback server cannot listen on it.
+    ///
+    /// # Fields
+    /// - `host`: The host of the redirect URI.
+    #[cfg(feature = "callback_server")]
+    #[error("the redirect URI host {host} is not a loopback address")]
+    NonLoopbackRedirectUri { host: String },
+}
+
+impl AuthError {
+    /// Creates an error from an OAuth error code and its optional description.
+    ///
+    /// These are returned by the Spotify Accounts service either in the query string of the
+    /// redirect URI, or in the JSON body of a failed token request.
+    pub(crate) fn from_oauth_error(error: &str, description: Option<String>) -> Self {
+        match error {
+            "access_denied" => Self::AuthorizationDenied { description },
+            "invalid_request" => Self::InvalidRequest { description },
+            "invalid_client" => Self::InvalidClient { description },
+            "invalid_grant" => Self::InvalidGrant { description },
+            "unauthorized_client" => Self::UnauthorizedClient { description },
+            "unsupported_grant_type" => Self::UnsupportedGrantType { description },
+            "invalid_scope" => Self::InvalidScope { description },
+            _ => Self::OAuth {
+                error: error.to_owned(),
+                description,
+            },
+        }
+    }
+
+    /// Returns the OAuth error code and description this error was created from, if any.
+    pub(crate) fn oauth_error(&self) -> Option<(&str, Option<&str>)> {
+        let (error, description) = match self {
+            Self::AuthorizationDenied { description } => ("access_denied", description),
+            Self::InvalidRequest { description } => ("invalid_request", description),
+            Self::InvalidClient { description } => ("invalid_client", description),
+            Self::InvalidGrant { description } => ("invalid_grant", description),
+            Self::UnauthorizedClient { description } => ("unauthorized_client", description),
+            Self::UnsupportedGrantType { description } => ("unsupported_grant_type", description),
+            Self::InvalidScope { description } => ("invalid_scope", description),
+            Self::OAuth { error, description } => (error.as_str(), description),
+            _ => return None,
+        };
+
+        Some((error, description.as_deref()))
+    }
 }
 
 pub(crate) mod private {
     use super::AuthError;
-    use crate::{RestError, api::ApiError, model::Token};
+    use crate::{
+        api::{ApiError, AsyncClient, Client},
+        model::Token,
+    };
     use async_trait::async_trait;
-    use reqwest::blocking::Client;
 
-    pub trait AuthFlow {
-        fn refresh_token(
+    pub trait AuthFlow: Clone {
+        /// Forgets the state of any authorization in progress.
+        fn reset(&mut self) {}
+
+        fn refresh_token<C>(
             &self,
-            client: &Client,
+            client: &C,
             refresh_token: &str,
-        ) -> Result<Token, ApiError<RestError>> {
+        ) -> Result<Token, ApiError<C::Error>>
+        where
+            C: Client,
+        {
             let _ = client;
             let _ = refresh_token;
             Err(AuthError::EmptyRefreshToken.into())
         }
+
+        /// Requests a brand-new token without any user interaction.
+        ///
+        /// Returns `None` if the flow cannot obtain a token on its own.
+        fn reissue_token<C>(&self, client: &C) -> Option<Result<Token, ApiError<C::Error>>>
+        where
+            C: Client,
+        {
+            let _ = client;
+            None
+        }
     }
 
     #[async_trait]
-    pub trait AsyncAuthFlow {
-        async fn refresh_token_async(
+    pub trait AsyncAuthFlow: Clone {
+        /// Forgets the state of any authorization in progress.
+        fn reset(&mut self) {}
+
+        async fn refresh_token_async<C>(
             &self,
-            client: &reqwest::Client,
+            client: &C,
             refresh_token: &str,
-        ) -> Result<Token, ApiError<RestError>> {
+        ) -> Result<Token, ApiError<C::Error>>
+        where
+            C: AsyncClient + Sync,
+        {
             let _ = client;
             let _ = refresh_token;
             Err(AuthError::EmptyRefreshToken.into())
         }
-    }
-}
-
-fn request_token(
-    client: &Client,
-    authorization_header: Option<String>,
-    params: FormParams<'_>,
-) -> Result<Token, ApiError<RestError>> {
-    let (request, data) = init_http_request_and_data(authorization_header, params)?;
-    let response = send_http_request(client, request, data).map_err(ApiError::client)?;
-    parse_http_response(&response)
-}
-
-async fn request_token_async(
-    client: &reqwest::Client,
-    authorization_header: Option<String>,
-    params: FormParams<'_>,
-) -> Result<Token, ApiError<RestError>> {
-    let (request, data) = init_http_request_and_data(authorization_header, params)?;
-    let response = send_http_request_async(client, request, data)
-        .await
-        .map_err(ApiError::client)?;
-    parse_http_response(&response)
-}
-
-fn set_authorization_header<'a>(
-    headers: &'a mut HeaderMap<HeaderValue>,
-    value: &str,
-) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
-    let mut header_value = HeaderValue::from_str(value)?;
-    header_value.set_sensitive(true);
-    headers.insert(header::AUTHORIZATION, header_value);
-    Ok(headers)
-}
-
-fn init_http_request_and_data(
-    authorization_header: Option<String>,
-    params: FormParams<'_>,
-) -> Result<(Builder, Vec<u8>), ApiError<RestError>> {
-    let url = Url::parse("https://accounts.spotify.com/api/token")?;
-
-    let mut req = Request::builder()
-        .method(http::Method::POST)
-        .uri(query::url_to_http_uri(&url));
-
-    if let Some(value) = authorization_header {
-        set_authorization_header(
-            req.headers_mut()
-                .expect("failed to get headers on the request builder"),
-            &value,
-        )?;
-    }
-
-    let (mime, data) = params
-        .into_body()?
-        .map_or((None, Vec::new()), |(mime, data)| {
-            (Some(mime), data.clone())
-        });
-
-    if let Some(mime) = mime {
-        req = req.header(header::CONTENT_TYPE, mime);
-    }
-
-    req = req.header(header::CONTENT_LENGTH, data.len().to_string());
 
-    Ok((req, data))
-}
-
-fn send_http_request(
-    client: &Client,
-    request: Builder,
-    data: Vec<u8>,
-) -> Result<http::Response<Bytes>, RestError> {
-    let http_request = request.body(data)?;
-    let request = http_request.try_into()?;
-    let response = client.execute(request)?;
-
-    let mut http_response = HttpResponse::builder()
-        .status(response.status())
-        .version(response.version());
-
-    let headers = http_response
-        .headers_mut()
-        .expect("failed to get headers on the request builder");
-
-    for (key, value) in response.headers() {
-        headers.insert(key, value.clone());
-    }
-
-    Ok(http_response.body(response.bytes()?)?)
-}
-
-async fn send_http_request_async(
-    client: &reqwest::Client,
-    request: Builder,
-    data: Vec<u8>,
-) -> Result<http::Response<Bytes>, RestError> {
-    let http_request = request.body(data)?;
-    let request = http_request.try_into()?;
-    let response = client.execute(request).await?;
-
-    let mut http_response = HttpResponse::builder()
-        .status(response.status())
-        .version(response.version());
-
-    let headers = http_response
-        .headers_mut()
-        .expect("failed to get headers on the request builder");
-
-    for (key, value) in response.headers() {
-        headers.insert(key, value.clone());
-    }
-
-    Ok(http_response.body(response.bytes().await?)?)
-}
-
-fn parse_http_response<T>(response: &http::Response<Bytes>) -> Result<T, ApiError<RestError>>
-where
-    T: serde::de::DeserializeOwned,
-{
-    let status = response.status();
-
-    let v = serde_json::from_slice(response.body())
-        .map_err(|_e| ApiError::server_error(status, response.body()))?;
-
-    if !status.is_success() {
-        return Err(ApiError::from_spotify_with_status(status, v));
-    } else if status == http::StatusCode::MOVED_PERMANENTLY {
-        return Err(ApiError::moved_permanently(
-            response.headers().get(header::LOCATION),
-        ));
+        /// Requests a brand-new token without any user interaction.
+        ///
+        /// Returns `None` if the flow cannot obtain a token on its own.
+        async fn reissue_token_async<C>(
+            &self,
+            client: &C,
+        ) -> Option<Result<Token, ApiError<C::Error>>>
+        where
+            C: AsyncClient + Sync,
+        {
+            let _ = client;
+            None
+        }
     }
-
-    serde_json::from_value::<_>(v).map_err(ApiError::data_type::<T>)
 }
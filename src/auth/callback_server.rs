@@ -0,0 +1,548 @@
+//! A local HTTP server that captures the OAuth redirect.
+//!
+//! Instead of asking the user to paste the URL they were redirected to, the [`CallbackServer`]
+//! binds to the host and port of the redirect URI, opens the authorization URL in the user's
+//! browser, and waits for Spotify to redirect back to it.
+//!
+//! The redirect URI registered for your application must point to a loopback address,
+//! such as `http://127.0.0.1:8888/callback`.
+//!
+//! This module is only available with the `callback_server` feature.
+
+use super::{AuthError, AuthResult};
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{ErrorKind, Read as _, Write as _},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use url::{Host, Url};
+
+const DEFAULT_SUCCESS_PAGE: &str = "<!DOCTYPE html><html><head><title>Authorization successful</title></head><body><h1>Authorization successful</h1><p>You can now close this tab and return to the application.</p></body></html>";
+
+const DEFAULT_FAILURE_PAGE: &str = "<!DOCTYPE html><html><head><title>Authorization failed</title></head><body><h1>Authorization failed</h1><p>You can now close this tab and return to the application.</p></body></html>";
+
+const NOT_FOUND_PAGE: &str = "<!DOCTYPE html><html><head><title>Not found</title></head><body><h1>Not found</h1></body></html>";
+
+/// The interval at which the blocking server checks for incoming connections.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The maximum size of the request head read from the browser.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// A handler called with the authorization URL when it was not opened in the browser.
+type AuthorizationUrlHandler = dyn Fn(&str) + Send + Sync + 'static;
+
+/// A loopback HTTP server used to capture the authorization callback.
+///
+/// # Example
+/// ```no_run
+/// use spotify_web_api::{Spotify, auth::{callback_server::CallbackServer, scopes}};
+/// use std::time::Duration;
+///
+/// let mut spotify = Spotify::with_authorization_code_pkce(
+///     "client_id",
+///     "http://127.0.0.1:8888/callback",
+///     scopes::user_details(),
+/// )
+/// .expect("Failed to create Spotify client");
+///
+/// let server = CallbackServer::new()
+///     .timeout(Duration::from_secs(60))
+///     .on_authorization_url(|url| println!("Please open {url} to authorize the application"));
+///
+/// spotify
+///     .authorize_with_callback_server(&server)
+///     .expect("Failed to authorize");
+/// ```
+#[derive(Clone)]
+pub struct CallbackServer {
+    /// The HTML page served when the redirect contains an authorization code.
+    success_page: String,
+
+    /// The HTML page served when the redirect contains an error or an unexpected `state`.
+    failure_page: String,
+
+    /// How long to wait for the redirect before giving up.
+    timeout: Duration,
+
+    /// Whether to open the authorization URL in the default browser.
+    ///
+    /// If disabled, or if the browser cannot be opened, the URL is passed to
+    /// `authorization_url_handler`.
+    open_browser: bool,
+
+    /// Called with the authorization URL when it was not opened in the browser.
+    ///
+    /// If not set, the URL is logged at the info level instead.
+    authorization_url_handler: Option<Arc<AuthorizationUrlHandler>>,
+}
+
+impl fmt::Debug for CallbackServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackServer")
+            .field("success_page", &self.success_page)
+            .field("failure_page", &self.failure_page)
+            .field("timeout", &self.timeout)
+            .field("open_browser", &self.open_browser)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for CallbackServer {
+    fn default() -> Self {
+        Self {
+            success_page: DEFAULT_SUCCESS_PAGE.to_owned(),
+            failure_page: DEFAULT_FAILURE_PAGE.to_owned(),
+            timeout: Duration::from_secs(300),
+            open_browser: true,
+            authorization_url_handler: None,
+        }
+    }
+}
+
+impl CallbackServer {
+    /// Creates a server with the default pages, a 5 minute timeout, and browser opening enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the HTML page served when the user granted access.
+    #[must_use]
+    pub fn success_page(mut self, html: impl Into<String>) -> Self {
+        self.success_page = html.into();
+        self
+    }
+
+    /// Sets the HTML page served when the user denied access or the redirect is invalid.
+    #[must_use]
+    pub fn failure_page(mut self, html: impl Into<String>) -> Self {
+        self.failure_page = html.into();
+        self
+    }
+
+    /// Sets how long to wait for the redirect before failing with [`AuthError::CallbackTimeout`].
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets whether the authorization URL is opened in the default browser.
+    ///
+    /// If disabled, the URL is passed to the [`on_authorization_url`](Self::on_authorization_url)
+    /// handler instead.
+    #[must_use]
+    pub fn open_browser(mut self, open_browser: bool) -> Self {
+        self.open_browser = open_browser;
+        self
+    }
+
+    /// Sets a handler called with the authorization URL when it was not opened in the browser,
+    /// for example to print it or show it in the application's interface.
+    ///
+    /// Without a handler, the URL is logged at the info level.
+    #[must_use]
+    pub fn on_authorization_url(mut self, handler: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.authorization_url_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Opens the authorization URL and blocks until the browser is redirected to `redirect_uri`.
+    ///
+    /// Redirects that do not carry the `state` of the authorization URL, or carry neither a
+    /// `code` nor an `error`, are ignored.
+    ///
+    /// # Returns
+    /// The full URL the browser was redirected to, including the query parameters.
+    ///
+    /// # Errors
+    /// * `AuthError::UrlParse` - If the redirect URI is not a valid URL.
+    /// * `AuthError::CallbackServer` - If binding to the redirect URI or accepting a connection fails.
+    /// * `AuthError::CallbackTimeout` - If no redirect was received before the timeout.
+    pub fn wait_for_redirect(
+        &self,
+        redirect_uri: &str,
+        authorization_url: &str,
+    ) -> AuthResult<Url> {
+        let redirect_uri = Url::parse(redirect_uri)?;
+        let state = expected_state(authorization_url)?;
+        let listener = TcpListener::bind(bind_address(&redirect_uri)?)?;
+        listener.set_nonblocking(true)?;
+
+        self.present_authorization_url(authorization_url);
+
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if let Some(url) =
+                        self.handle_connection(stream, &redirect_uri, state.as_deref())
+                    {
+                        return Ok(url);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(AuthError::CallbackTimeout);
+                    }
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Opens the authorization URL and waits asynchronously until the browser is redirected to
+    /// `redirect_uri`.
+    ///
+    /// Redirects that do not carry the `state` of the authorization URL, or carry neither a
+    /// `code` nor an `error`, are ignored.
+    ///
+    /// This method must be called from within a Tokio runtime.
+    ///
+    /// # Returns
+    /// The full URL the browser was redirected to, including the query parameters.
+    ///
+    /// # Errors
+    /// * `AuthError::UrlParse` - If the redirect URI is not a valid URL.
+    /// * `AuthError::CallbackServer` - If binding to the redirect URI or accepting a connection fails.
+    /// * `AuthError::CallbackTimeout` - If no redirect was received before the timeout.
+    pub async fn wait_for_redirect_async(
+        &self,
+        redirect_uri: &str,
+        authorization_url: &str,
+    ) -> AuthResult<Url> {
+        let redirect_uri = Url::parse(redirect_uri)?;
+        let state = expected_state(authorization_url)?;
+        let listener = tokio::net::TcpListener::bind(bind_address(&redirect_uri)?).await?;
+
+        self.present_authorization_url(authorization_url);
+
+        let accept = async {
+            loop {
+                let (stream, _) = listener.accept().await?;
+                if let Some(url) = self
+                    .handle_connection_async(stream, &redirect_uri, state.as_deref())
+                    .await
+                {
+                    return Ok(url);
+                }
+            }
+        };
+
+        tokio::time::timeout(self.timeout, accept)
+            .await
+            .map_err(|_elapsed| AuthError::CallbackTimeout)?
+    }
+
+    fn present_authorization_url(&self, authorization_url: &str) {
+        if self.open_browser && webbrowser::open(authorization_url).is_ok() {
+            return;
+        }
+
+        match &self.authorization_url_handler {
+            Some(handler) => handler(authorization_url),
+            None => log::info!("navigate to the following URL to authorize: {authorization_url}"),
+        }
+    }
+
+    fn handle_connection(
+        &self,
+        mut stream: TcpStream,
+        redirect_uri: &Url,
+        state: Option<&str>,
+    ) -> Option<Url> {
+        stream.set_nonblocking(false).ok()?;
+        stream.set_read_timeout(Some(Duration::from_secs(5))).ok()?;
+
+        let mut buffer = Vec::new();
+        let mut chunk = [0; 1024];
+
+        while !is_complete_request(&buffer) {
+            match stream.read(&mut chunk) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+            }
+        }
+
+        let (response, url) = self.respond(&buffer, redirect_uri, state);
+
+        if let Err(e) = stream
+            .write_all(response.as_bytes())
+            .and_then(|()| stream.flush())
+        {
+            log::warn!("failed to write the callback response: {e}");
+        }
+
+        url
+    }
+
+    async fn handle_connection_async(
+        &self,
+        mut stream: tokio::net::TcpStream,
+        redirect_uri: &Url,
+        state: Option<&str>,
+    ) -> Option<Url> {
+        use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+        let mut buffer = Vec::new();
+        let mut chunk = [0; 1024];
+
+        while !is_complete_request(&buffer) {
+            let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut chunk)).await;
+            match read {
+                Ok(Ok(n)) if n > 0 => buffer.extend_from_slice(&chunk[..n]),
+                _ => break,
+            }
+        }
+
+        let (response, url) = self.respond(&buffer, redirect_uri, state);
+
+        let write = async {
+            stream.write_all(response.as_bytes()).await?;
+            stream.flush().await
+        };
+
+        if let Err(e) = write.await {
+            log::warn!("failed to write the callback response: {e}");
+        }
+
+        url
+    }
+
+    /// Builds the HTTP response for a request and extracts the callback URL, if any.
+    ///
+    /// Only a redirect carrying a `code` or an `error`, and the `state` of the authorization URL,
+    /// ends the wait. Other requests, such as for `/favicon.ico` or with a forged `state`, are
+    /// answered with an error page and ignored.
+    fn respond(
+        &self,
+        request: &[u8],
+        redirect_uri: &Url,
+        state: Option<&str>,
+    ) -> (String, Option<Url>) {
+        let Some(url) = parse_callback_url(request, redirect_uri) else {
+            return (http_response("404 Not Found", NOT_FOUND_PAGE), None);
+        };
+
+        if query_param(&url, "code").is_none() && query_param(&url, "error").is_none() {
+            return (http_response("404 Not Found", NOT_FOUND_PAGE), None);
+        }
+
+        if let Some(state) = state
+            && query_param(&url, "state").as_deref() != Some(state)
+        {
+            log::warn!("ignoring a callback request with an unexpected state");
+            return (http_response("400 Bad Request", &self.failure_page), None);
+        }
+
+        let response = if query_param(&url, "code").is_some() {
+            http_response("200 OK", &self.success_page)
+        } else {
+            http_response("400 Bad Request", &self.failure_page)
+        };
+
+        (response, Some(url))
+    }
+}
+
+/// Returns the `state` parameter of the authorization URL, which the redirect must carry back.
+fn expected_state(authorization_url: &str) -> AuthResult<Option<String>> {
+    let url = Url::parse(authorization_url)?;
+    Ok(query_param(&url, "state").map(Cow::into_owned))
+}
+
+/// Returns the value of a query parameter of a URL.
+fn query_param<'a>(url: &'a Url, name: &str) -> Option<Cow<'a, str>> {
+    url.query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+}
+
+/// Returns the `host:port` pair to listen on for the given redirect URI.
+///
+/// Only loopback hosts are accepted, so that the server is never reachable from the network.
+fn bind_address(redirect_uri: &Url) -> AuthResult<String> {
+    let host = redirect_uri
+        .host()
+        .ok_or(AuthError::UrlParse(url::ParseError::EmptyHost))?;
+
+    let is_loopback = match &host {
+        Host::Domain(domain) => domain.eq_ignore_ascii_case("localhost"),
+        Host::Ipv4(ip) => ip.is_loopback(),
+        Host::Ipv6(ip) => ip.is_loopback(),
+    };
+
+    if !is_loopback {
+        return Err(AuthError::NonLoopbackRedirectUri {
+            host: host.to_string(),
+        });
+    }
+
+    let port = redirect_uri
+        .port_or_known_default()
+        .ok_or(AuthError::UrlParse(url::ParseError::InvalidPort))?;
+
+    Ok(format!("{host}:{port}"))
+}
+
+fn is_complete_request(buffer: &[u8]) -> bool {
+    buffer.len() >= MAX_REQUEST_SIZE || buffer.windows(4).any(|w| w == b"\r\n\r\n")
+}
+
+/// Extracts the callback URL from a raw HTTP request targeting the redirect URI's path.
+fn parse_callback_url(request: &[u8], redirect_uri: &Url) -> Option<Url> {
+    let request = String::from_utf8_lossy(request);
+    let mut parts = request.lines().next()?.split_whitespace();
+
+    if parts.next()? != "GET" {
+        return None;
+    }
+
+    let url = redirect_uri.join(parts.next()?).ok()?;
+
+    (url.path() == redirect_uri.path()).then_some(url)
+}
+
+fn http_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_callback_url_with_code() {
+        let redirect_uri = Url::parse("http://127.0.0.1:8888/callback").unwrap();
+        let request = b"GET /callback?code=abc&state=xyz HTTP/1.1\r\nHost: 127.0.0.1:8888\r\n\r\n";
+
+        let url = parse_callback_url(request, &redirect_uri).unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "http://127.0.0.1:8888/callback?code=abc&state=xyz"
+        );
+    }
+
+    #[test]
+    fn parse_callback_url_ignores_other_paths() {
+        let redirect_uri = Url::parse("http://127.0.0.1:8888/callback").unwrap();
+        let request = b"GET /favicon.ico HTTP/1.1\r\nHost: 127.0.0.1:8888\r\n\r\n";
+
+        assert!(parse_callback_url(request, &redirect_uri).is_none());
+    }
+
+    #[test]
+    fn respond_serves_failure_page_on_error() {
+        let redirect_uri = Url::parse("http://127.0.0.1:8888/callback").unwrap();
+        let request = b"GET /callback?error=access_denied&state=xyz HTTP/1.1\r\n\r\n";
+        let server = CallbackServer::new().failure_page("denied");
+
+        let (response, url) = server.respond(request, &redirect_uri, Some("xyz"));
+
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+        assert!(response.ends_with("denied"));
+        assert!(url.is_some());
+    }
+
+    #[test]
+    fn respond_ignores_unexpected_state() {
+        let redirect_uri = Url::parse("http://127.0.0.1:8888/callback").unwrap();
+        let request = b"GET /callback?code=abc&state=forged HTTP/1.1\r\n\r\n";
+        let server = CallbackServer::new().success_page("granted");
+
+        let (response, url) = server.respond(request, &redirect_uri, Some("xyz"));
+
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+        assert!(!response.ends_with("granted"));
+        assert!(url.is_none());
+
+        let request = b"GET /callback?code=abc&state=xyz HTTP/1.1\r\n\r\n";
+
+        let (response, url) = server.respond(request, &redirect_uri, Some("xyz"));
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("granted"));
+        assert!(url.is_some());
+    }
+
+    #[test]
+    fn respond_ignores_requests_without_code_or_error() {
+        let redirect_uri = Url::parse("http://127.0.0.1:8888/callback").unwrap();
+        let request = b"GET /callback?state=xyz HTTP/1.1\r\n\r\n";
+        let server = CallbackServer::new();
+
+        let (response, url) = server.respond(request, &redirect_uri, Some("xyz"));
+
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+        assert!(url.is_none());
+    }
+
+    #[test]
+    fn expected_state_is_read_from_authorization_url() {
+        let url = "https://accounts.spotify.com/authorize?client_id=abc&state=xyz";
+
+        assert_eq!(expected_state(url).unwrap().as_deref(), Some("xyz"));
+        assert_eq!(
+            expected_state("https://accounts.spotify.com/authorize").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn bind_address_uses_default_port() {
+        let redirect_uri = Url::parse("http://localhost/callback").unwrap();
+        assert_eq!(bind_address(&redirect_uri).unwrap(), "localhost:80");
+    }
+
+    #[test]
+    fn bind_address_accepts_only_loopback_hosts() {
+        for uri in [
+            "http://127.0.0.1:8888/callback",
+            "http://[::1]:8888/callback",
+            "http://LOCALHOST:8888/callback",
+        ] {
+            assert!(bind_address(&Url::parse(uri).unwrap()).is_ok(), "{uri}");
+        }
+
+        for uri in [
+            "http://0.0.0.0:8888/callback",
+            "http://192.168.1.10:8888/callback",
+            "https://example.com/callback",
+        ] {
+            assert!(
+                matches!(
+                    bind_address(&Url::parse(uri).unwrap()),
+                    Err(AuthError::NonLoopbackRedirectUri { .. })
+                ),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn authorization_url_is_passed_to_handler() {
+        let url = Arc::new(parking_lot::Mutex::new(None));
+        let server = CallbackServer::new()
+            .open_browser(false)
+            .on_authorization_url({
+                let url = Arc::clone(&url);
+                move |authorization_url| *url.lock() = Some(authorization_url.to_owned())
+            });
+
+        server.present_authorization_url("https://accounts.spotify.com/authorize");
+
+        assert_eq!(
+            url.lock().as_deref(),
+            Some("https://accounts.spotify.com/authorize")
+        );
+    }
+}
@@ -1,11 +1,15 @@
-use super::private::{AsyncAuthFlow, AuthFlow};
+use super::{
+    Secret,
+    private::{AsyncAuthFlow, AuthFlow},
+};
 use crate::{
-    RestError,
-    api::{ApiError, FormParams},
+    api::{
+        ApiError, AsyncClient, AsyncQuery as _, Client, Query as _,
+        token::RequestClientCredentialsToken,
+    },
     model::Token,
 };
-use base64::{Engine as _, engine::general_purpose};
-use reqwest::blocking::Client;
+use async_trait::async_trait;
 
 /// Represents the Client Credentials authentication flow for Spotify.
 ///
@@ -13,6 +17,7 @@ use reqwest::blocking::Client;
 /// which is commonly used for server-to-server requests where user authorization is not required.
 /// This flow involves sending a request to the Spotify Accounts service to obtain an access token
 /// using the client ID and client secret.
+#[derive(Clone)]
 pub struct ClientCredentials {
     /// The Client ID generated after registering your application.
     ///
@@ -22,41 +27,84 @@ pub struct ClientCredentials {
     /// The Client Secret generated after registering your application.
     ///
     /// This is required to authenticate your application to the Spotify API.
-    client_secret: String,
+    client_secret: Secret<String>,
 }
 
-impl AuthFlow for ClientCredentials {}
-impl AsyncAuthFlow for ClientCredentials {}
-
 impl ClientCredentials {
     pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
         Self {
             client_id: client_id.into(),
-            client_secret: client_secret.into(),
+            client_secret: Secret::new(client_secret.into()),
         }
     }
 
-    pub fn request_token(&self, client: &Client) -> Result<Token, ApiError<RestError>> {
-        let (auth, params) = self.auth_value_and_params();
-        super::request_token(client, Some(auth), params)
+    pub fn request_token<C>(&self, client: &C) -> Result<Token, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        self.token_request().query(client)
+    }
+
+    pub async fn request_token_async<C>(&self, client: &C) -> Result<Token, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        self.token_request().query_async(client).await
     }
 
-    pub async fn request_token_async(
-        &self,
-        client: &reqwest::Client,
-    ) -> Result<Token, ApiError<RestError>> {
-        let (auth, params) = self.auth_value_and_params();
-        super::request_token_async(client, Some(auth), params).await
+    fn token_request(&self) -> RequestClientCredentialsToken {
+        RequestClientCredentialsToken::new(&self.client_id, self.client_secret.expose_secret())
+    }
+}
+
+impl AuthFlow for ClientCredentials {
+    fn reissue_token<C>(&self, client: &C) -> Option<Result<Token, ApiError<C::Error>>>
+    where
+        C: Client,
+    {
+        Some(self.request_token(client))
     }
+}
+
+#[async_trait]
+impl AsyncAuthFlow for ClientCredentials {
+    async fn reissue_token_async<C>(&self, client: &C) -> Option<Result<Token, ApiError<C::Error>>>
+    where
+        C: AsyncClient + Sync,
+    {
+        Some(self.request_token_async(client).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::UrlBase,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+    use http::Method;
+
+    #[test]
+    fn reissue_token_is_sent_through_the_client() {
+        let endpoint = ExpectedUrl::builder()
+            .url_base(UrlBase::Accounts)
+            .endpoint("api/token")
+            .method(Method::POST)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("grant_type=client_credentials")
+            .build();
 
-    fn auth_value_and_params(&self) -> (String, FormParams<'_>) {
-        let credentials = format!("{}:{}", self.client_id, self.client_secret);
-        let mut auth = general_purpose::URL_SAFE_NO_PAD.encode(credentials);
-        auth.insert_str(0, "Basic ");
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#,
+        );
 
-        let mut params = FormParams::default();
-        params.push("grant_type", &"client_credentials");
+        let token = ClientCredentials::new("id", "secret")
+            .reissue_token(&client)
+            .unwrap()
+            .unwrap();
 
-        (auth, params)
+        assert_eq!(token.access_token.expose_secret(), "token");
     }
 }
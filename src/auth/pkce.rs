@@ -1,18 +1,145 @@
 use super::{
-    AuthError, AuthResult,
+    AuthError, AuthResult, Secret,
     private::{AsyncAuthFlow, AuthFlow},
 };
 use crate::{
-    RestError,
-    api::{ApiError, FormParams, QueryParams},
+    api::{
+        ApiError, AsyncClient, AsyncQuery as _, Client, Query as _, QueryParams,
+        token::{RefreshAccessToken, RequestAccessToken},
+    },
     auth::scopes::{self, Scope},
     model::Token,
 };
 use async_trait::async_trait;
-use reqwest::blocking::Client;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use url::Url;
 
+/// How long a pending authorization stays valid after the authorization URL was generated.
+const PENDING_AUTHORIZATION_TTL: TimeDelta = TimeDelta::minutes(10);
+
+/// The state of an authorization request that has not been completed yet.
+///
+/// Generating a user authorization URL creates a `state` and a `code_verifier` that are needed
+/// again when the user is redirected back to the application. In web applications the
+/// redirect is often handled by a different request, or even a different process, than the one
+/// that generated the URL. This struct can be serialized (for example into a session store or
+/// a cookie) and restored into a fresh client to complete the token exchange.
+///
+/// # Security
+/// The `code_verifier` is a secret: store the pending authorization server-side or encrypted,
+/// and never send it to the browser in clear text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingAuthorization {
+    /// The `state` parameter sent in the authorization URL.
+    pub state: String,
+
+    /// The code verifier matching the `code_challenge` sent in the authorization URL.
+    ///
+    /// It is redacted from the `Debug` output, but serialized as the plain value.
+    pub code_verifier: Secret<String>,
+
+    /// The redirect URI sent in the authorization URL. It must be sent again in the token request.
+    pub redirect_uri: String,
+
+    /// The scopes requested in the authorization URL.
+    pub scopes: Option<HashSet<Scope>>,
+
+    /// When the authorization URL was generated.
+    pub created_at: DateTime<Utc>,
+
+    /// When the pending authorization stops being accepted.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingAuthorization {
+    /// Checks if the pending authorization has expired.
+    ///
+    /// # Returns
+    /// - `true`: If the expiration time has passed.
+    /// - `false`: If the pending authorization can still be completed.
+    pub fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at
+    }
+}
+
+/// The length of the generated `state` parameter.
+const STATE_LENGTH: usize = 16;
+
+/// The default length of the generated code verifier.
+const CODE_VERIFIER_LENGTH: usize = 128;
+
+/// Options customizing the user authorization URL.
+///
+/// # Example
+/// ```
+/// use spotify_web_api::auth::pkce::AuthorizationUrlOptions;
+///
+/// let options = AuthorizationUrlOptions::new()
+///     .show_dialog(true)
+///     .state("signed-return-to-payload")
+///     .code_verifier_length(64);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationUrlOptions {
+    /// Whether to force the user to approve the app again, even if they already did.
+    show_dialog: bool,
+
+    /// A custom `state` parameter. A random one is generated if not set.
+    state: Option<String>,
+
+    /// The length of the generated code verifier, between 43 and 128 characters.
+    code_verifier_length: usize,
+}
+
+impl Default for AuthorizationUrlOptions {
+    fn default() -> Self {
+        Self {
+            show_dialog: false,
+            state: None,
+            code_verifier_length: CODE_VERIFIER_LENGTH,
+        }
+    }
+}
+
+impl AuthorizationUrlOptions {
+    /// Creates options matching the defaults of `user_authorization_url()`: no `show_dialog`,
+    /// a random 16 character state, and a 128 character code verifier.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the user is forced to approve the app again.
+    ///
+    /// If `false` (the default), a user who already approved the app may be redirected
+    /// automatically. Set it to `true` to let users switch accounts.
+    #[must_use]
+    pub fn show_dialog(mut self, show_dialog: bool) -> Self {
+        self.show_dialog = show_dialog;
+        self
+    }
+
+    /// Sets a custom `state` parameter, such as a signed payload carrying a return-to path.
+    ///
+    /// The state is still checked by `verify_authorization_code()`, so it protects against
+    /// CSRF only if it cannot be guessed: include a random nonce or a signature in it.
+    #[must_use]
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Sets the length of the generated code verifier.
+    ///
+    /// The length is clamped to the 43 to 128 characters allowed by RFC 7636.
+    #[must_use]
+    pub fn code_verifier_length(mut self, length: usize) -> Self {
+        self.code_verifier_length = length;
+        self
+    }
+}
+
 /// Represents the Authorization Code Flow with Proof Key for Code Exchange (PKCE).
 ///
 /// This flow is used to securely authenticate users and obtain access tokens for the Spotify Web API.
@@ -41,6 +168,12 @@ pub struct AuthCodePKCE {
     /// This includes content visible in the Spotify desktop, web, and mobile players.
     scopes: Option<HashSet<Scope>>,
 
+    /// The scopes requested in the current authorization URL.
+    ///
+    /// They only replace the configured `scopes` once the authorization is completed, so that
+    /// a request for more scopes that the user denies leaves the configuration unchanged.
+    requested_scopes: Option<HashSet<Scope>>,
+
     /// A cryptographically secure random string to be used as the `state` parameter.
     ///
     /// The `state` parameter ensures that the response to the authorization request is not
@@ -59,7 +192,10 @@ pub struct AuthCodePKCE {
     /// verify the integrity of the exchange.
     ///
     /// For more details, see [RFC 7636](https://datatracker.ietf.org/doc/html/rfc7636).
-    code_verifier: Option<String>,
+    code_verifier: Option<Secret<String>>,
+
+    /// When the current authorization URL was generated, and when it stops being accepted.
+    authorization_window: Option<(DateTime<Utc>, DateTime<Utc>)>,
 }
 
 impl AuthCodePKCE {
@@ -74,8 +210,10 @@ impl AuthCodePKCE {
             client_id: client_id.into(),
             redirect_uri: redirect_uri.into(),
             scopes: scopes.into(),
+            requested_scopes: None,
             state: None,
             code_verifier: None,
+            authorization_window: None,
         }
     }
 
@@ -83,10 +221,39 @@ impl AuthCodePKCE {
         self.scopes = scopes;
     }
 
+    pub fn scopes(&self) -> Option<&HashSet<Scope>> {
+        self.scopes.as_ref()
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Returns the scopes requested in the current authorization URL.
+    pub(crate) fn requested_scopes(&self) -> Option<&HashSet<Scope>> {
+        self.requested_scopes.as_ref()
+    }
+
     pub fn user_authorization_url(&mut self) -> String {
-        let code_verifier = crypto::generate_code_verifier(128);
+        self.user_authorization_url_with(&AuthorizationUrlOptions::default())
+    }
+
+    pub fn user_authorization_url_with(&mut self, options: &AuthorizationUrlOptions) -> String {
+        self.authorization_url_with_scopes(options, self.scopes.clone())
+    }
+
+    /// Generates an authorization URL requesting `scopes` instead of the configured ones.
+    pub(crate) fn authorization_url_with_scopes(
+        &mut self,
+        options: &AuthorizationUrlOptions,
+        requested_scopes: Option<HashSet<Scope>>,
+    ) -> String {
+        let code_verifier = crypto::generate_code_verifier(options.code_verifier_length);
         let code_challenge = crypto::generate_code_challenge(&code_verifier);
-        let state = crypto::random_string(16);
+        let state = options
+            .state
+            .clone()
+            .unwrap_or_else(|| crypto::random_string(STATE_LENGTH));
 
         let mut params = QueryParams::default();
         params
@@ -94,21 +261,56 @@ impl AuthCodePKCE {
             .push("response_type", &"code")
             .push("redirect_uri", &self.redirect_uri)
             .push("state", &state)
-            .push_opt("scope", self.scopes.as_ref().map(scopes::to_string))
+            .push_opt("scope", requested_scopes.as_ref().map(scopes::to_string))
             .push("code_challenge_method", &"S256")
-            .push("code_challenge", &code_challenge);
+            .push("code_challenge", &code_challenge)
+            .push_opt("show_dialog", options.show_dialog.then_some(true));
 
         let mut url =
             Url::parse("https://accounts.spotify.com/authorize").expect("This URL is always valid");
 
         params.add_to_url(&mut url);
 
+        let now = Utc::now();
+
+        self.requested_scopes = requested_scopes;
         self.state = Some(state);
-        self.code_verifier = Some(code_verifier);
+        self.code_verifier = Some(Secret::new(code_verifier));
+        self.authorization_window = Some((now, now + PENDING_AUTHORIZATION_TTL));
 
         url.as_str().to_owned()
     }
 
+    pub fn pending_authorization(&self) -> Option<PendingAuthorization> {
+        let (created_at, expires_at) = self.authorization_window?;
+
+        Some(PendingAuthorization {
+            state: self.state.clone()?,
+            code_verifier: self.code_verifier.clone()?,
+            redirect_uri: self.redirect_uri.clone(),
+            scopes: self.requested_scopes.clone(),
+            created_at,
+            expires_at,
+        })
+    }
+
+    pub fn restore_pending_authorization(
+        &mut self,
+        pending: PendingAuthorization,
+    ) -> AuthResult<()> {
+        if pending.is_expired() {
+            return Err(AuthError::PendingAuthorizationExpired);
+        }
+
+        self.redirect_uri = pending.redirect_uri;
+        self.requested_scopes = pending.scopes;
+        self.state = Some(pending.state);
+        self.code_verifier = Some(pending.code_verifier);
+        self.authorization_window = Some((pending.created_at, pending.expires_at));
+
+        Ok(())
+    }
+
     pub fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
         let self_state = self.state.as_ref().ok_or(AuthError::NoState)?;
 
@@ -116,126 +318,139 @@ impl AuthCodePKCE {
 
         let mut code = None;
         let mut state = None;
+        let mut error = None;
+        let mut error_description = None;
 
         for (key, value) in url.query_pairs() {
             match key.as_ref() {
                 "code" => code = Some(value),
                 "state" => state = Some(value),
+                "error" => error = Some(value),
+                "error_description" => error_description = Some(value),
                 _ => {}
             }
         }
 
-        let code = code.ok_or(AuthError::CodeNotFound)?;
         let state = state.ok_or(AuthError::InvalidState {
             expected: self_state.to_owned(),
             got: "None".to_owned(),
         })?;
 
-        if self_state.eq(&state) {
-            Ok(code.to_string())
-        } else {
-            Err(AuthError::InvalidState {
+        if !self_state.eq(&state) {
+            return Err(AuthError::InvalidState {
                 expected: self_state.to_owned(),
                 got: state.to_string(),
-            })
+            });
         }
+
+        if let Some(error) = error {
+            return Err(AuthError::from_oauth_error(
+                &error,
+                error_description.map(|d| d.to_string()),
+            ));
+        }
+
+        code.map(|code| code.to_string())
+            .ok_or(AuthError::CodeNotFound)
     }
 
-    pub fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>> {
-        let code_verifier = self
-            .code_verifier
-            .as_ref()
-            .ok_or(AuthError::NoCodeVerifier)?;
-        let params = self.token_request_params(code, code_verifier);
-        super::request_token(client, None, params)
+    pub fn request_token<C>(&self, code: &str, client: &C) -> Result<Token, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        self.token_request(code)?.query(client)
     }
 
-    pub async fn request_token_async(
+    pub async fn request_token_async<C>(
         &self,
         code: &str,
-        client: &reqwest::Client,
-    ) -> Result<Token, ApiError<RestError>> {
-        let code_verifier = self
-            .code_verifier
-            .as_ref()
-            .ok_or(AuthError::NoCodeVerifier)?;
-        let params = self.token_request_params(code, code_verifier);
-        super::request_token_async(client, None, params).await
+        client: &C,
+    ) -> Result<Token, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        self.token_request(code)?.query_async(client).await
     }
 
-    pub fn request_token_from_redirect_url(
+    pub fn request_token_from_redirect_url<C>(
         &self,
         url: &str,
-        client: &Client,
-    ) -> Result<Token, ApiError<RestError>> {
+        client: &C,
+    ) -> Result<Token, ApiError<C::Error>>
+    where
+        C: Client,
+    {
         let code = self.verify_authorization_code(url)?;
-        let code_verifier = self
-            .code_verifier
-            .as_ref()
-            .ok_or(AuthError::NoCodeVerifier)?;
-        let params = self.token_request_params(&code, code_verifier);
-        super::request_token(client, None, params)
+        self.request_token(&code, client)
     }
 
-    pub async fn request_token_from_redirect_url_async(
+    pub async fn request_token_from_redirect_url_async<C>(
         &self,
         url: &str,
-        client: &reqwest::Client,
-    ) -> Result<Token, ApiError<RestError>> {
+        client: &C,
+    ) -> Result<Token, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
         let code = self.verify_authorization_code(url)?;
+        self.request_token_async(&code, client).await
+    }
+
+    /// Discards the `state` and code verifier of the current authorization URL.
+    ///
+    /// A redirect for that URL can no longer be exchanged for a token afterwards.
+    pub fn clear_pending_authorization(&mut self) {
+        self.requested_scopes = None;
+        self.state = None;
+        self.code_verifier = None;
+        self.authorization_window = None;
+    }
+
+    fn token_request(&self, code: &str) -> AuthResult<RequestAccessToken> {
         let code_verifier = self
             .code_verifier
             .as_ref()
             .ok_or(AuthError::NoCodeVerifier)?;
-        let params = self.token_request_params(&code, code_verifier);
-        super::request_token_async(client, None, params).await
-    }
 
-    fn token_request_params<'a>(&self, code: &'a str, code_verifier: &'a str) -> FormParams<'a> {
-        let mut params = FormParams::default();
-        params.push("grant_type", &"authorization_code");
-        params.push("code", &code);
-        params.push("redirect_uri", &self.redirect_uri);
-        params.push("client_id", &self.client_id);
-        params.push("code_verifier", &code_verifier);
-        params
-    }
-
-    fn refresh_token_request_params<'a>(&self, refresh_token: &'a str) -> FormParams<'a> {
-        let mut params = FormParams::default();
-        params.push("grant_type", &"refresh_token");
-        params.push("refresh_token", &refresh_token);
-        params.push("client_id", &self.client_id);
-        params
+        Ok(RequestAccessToken::new(
+            &self.client_id,
+            code,
+            &self.redirect_uri,
+            code_verifier.expose_secret(),
+        ))
     }
 }
 
 impl AuthFlow for AuthCodePKCE {
-    fn refresh_token(
-        &self,
-        client: &Client,
-        refresh_token: &str,
-    ) -> Result<Token, ApiError<RestError>> {
-        let params = self.refresh_token_request_params(refresh_token);
-        let (request, data) = super::init_http_request_and_data(None, params)?;
-        let response = super::send_http_request(client, request, data).map_err(ApiError::client)?;
-        super::parse_http_response(&response)
+    fn reset(&mut self) {
+        self.clear_pending_authorization();
+    }
+
+    fn refresh_token<C>(&self, client: &C, refresh_token: &str) -> Result<Token, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        RefreshAccessToken::new(&self.client_id, refresh_token).query(client)
     }
 }
 
 #[async_trait]
 impl AsyncAuthFlow for AuthCodePKCE {
-    async fn refresh_token_async(
+    fn reset(&mut self) {
+        self.clear_pending_authorization();
+    }
+
+    async fn refresh_token_async<C>(
         &self,
-        client: &reqwest::Client,
+        client: &C,
         refresh_token: &str,
-    ) -> Result<Token, ApiError<RestError>> {
-        let params = self.refresh_token_request_params(refresh_token);
-        let (request, data) = super::init_http_request_and_data(None, params)?;
-        let response = super::send_http_request_async(client, request, data)
+    ) -> Result<Token, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        RefreshAccessToken::new(&self.client_id, refresh_token)
+            .query_async(client)
             .await
-            .map_err(ApiError::client)?;
-        super::parse_http_response(&response)
     }
 }
 
@@ -273,10 +488,146 @@ mod crypto {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn random_string() {
         let length = 16;
-        let random_string = super::crypto::random_string(length);
+        let random_string = crypto::random_string(length);
         assert_eq!(random_string.len(), length);
     }
+
+    #[test]
+    fn pending_authorization_round_trip() {
+        let mut auth = AuthCodePKCE::new(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            scopes::user_details(),
+        );
+
+        assert!(auth.pending_authorization().is_none());
+
+        auth.user_authorization_url();
+
+        let pending = auth.pending_authorization().unwrap();
+        let code_verifier = pending.code_verifier.expose_secret();
+        assert!(!format!("{pending:?}").contains(code_verifier.as_str()));
+
+        let json = serde_json::to_string(&pending).unwrap();
+        assert!(json.contains(code_verifier.as_str()));
+        let restored: PendingAuthorization = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, pending);
+
+        let mut fresh = AuthCodePKCE::new("client_id", "http://other/callback", None);
+        fresh.restore_pending_authorization(restored).unwrap();
+
+        assert_eq!(fresh.redirect_uri(), "http://127.0.0.1:8888/callback");
+        assert_eq!(fresh.requested_scopes, Some(scopes::user_details()));
+
+        let url = format!(
+            "http://127.0.0.1:8888/callback?code=abc&state={}",
+            pending.state
+        );
+        assert_eq!(fresh.verify_authorization_code(&url).unwrap(), "abc");
+    }
+
+    #[test]
+    fn verify_authorization_code_access_denied() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+        auth.user_authorization_url();
+        let state = auth.state.clone().unwrap();
+
+        let url = format!("http://127.0.0.1:8888/callback?error=access_denied&state={state}");
+
+        assert!(matches!(
+            auth.verify_authorization_code(&url),
+            Err(AuthError::AuthorizationDenied { description: None })
+        ));
+    }
+
+    #[test]
+    fn verify_authorization_code_error_checks_state_first() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+        auth.user_authorization_url();
+
+        let url = "http://127.0.0.1:8888/callback?error=access_denied&state=forged";
+
+        assert!(matches!(
+            auth.verify_authorization_code(url),
+            Err(AuthError::InvalidState { .. })
+        ));
+    }
+
+    #[test]
+    fn user_authorization_url_with_options() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+
+        let options = AuthorizationUrlOptions::new()
+            .show_dialog(true)
+            .state("return_to=/playlists&sig=abc")
+            .code_verifier_length(10);
+
+        let url = Url::parse(&auth.user_authorization_url_with(&options)).unwrap();
+        let params: Vec<_> = url.query_pairs().into_owned().collect();
+
+        assert!(params.contains(&("show_dialog".to_owned(), "true".to_owned())));
+        assert!(params.contains(&(
+            "state".to_owned(),
+            "return_to=/playlists&sig=abc".to_owned()
+        )));
+        assert_eq!(
+            auth.code_verifier.as_ref().unwrap().expose_secret().len(),
+            43
+        );
+
+        let callback = Url::parse_with_params(
+            "http://127.0.0.1:8888/callback",
+            [("code", "abc"), ("state", "return_to=/playlists&sig=abc")],
+        )
+        .unwrap();
+        assert_eq!(
+            auth.verify_authorization_code(callback.as_str()).unwrap(),
+            "abc"
+        );
+
+        let forged = "http://127.0.0.1:8888/callback?code=abc&state=return_to%3D%2F";
+        assert!(matches!(
+            auth.verify_authorization_code(forged),
+            Err(AuthError::InvalidState { .. })
+        ));
+    }
+
+    #[test]
+    fn user_authorization_url_defaults() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+
+        let url = auth.user_authorization_url();
+
+        assert!(!url.contains("show_dialog"));
+        assert_eq!(auth.state.as_ref().unwrap().len(), STATE_LENGTH);
+        assert_eq!(
+            auth.code_verifier.as_ref().unwrap().expose_secret().len(),
+            CODE_VERIFIER_LENGTH
+        );
+    }
+
+    #[test]
+    fn expired_pending_authorization_is_rejected() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+        auth.user_authorization_url();
+
+        let mut pending = auth.pending_authorization().unwrap();
+        pending.expires_at = Utc::now() - TimeDelta::seconds(1);
+
+        let mut fresh = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+
+        assert!(matches!(
+            fresh.restore_pending_authorization(pending),
+            Err(AuthError::PendingAuthorizationExpired)
+        ));
+        assert!(matches!(
+            fresh.verify_authorization_code("http://127.0.0.1:8888/callback?code=abc"),
+            Err(AuthError::NoState)
+        ));
+    }
 }
@@ -1,5 +1,7 @@
 //! OAuth2.0 Scopes for the Spotify Web API.
 
+use crate::api::Endpoint;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 /// Represents the various access scopes available in the Spotify Web API.
@@ -21,7 +23,8 @@ use std::collections::HashSet;
 ///     Scope::UserModifyPlaybackState,
 /// ]);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Scope {
     /// Upload images to a user's profile.
     ///
@@ -307,6 +310,91 @@ pub fn to_string(set: &HashSet<Scope>) -> String {
         .join(" ")
 }
 
+/// Parses a space-separated list of scopes, as returned in the `scope` field of a token.
+///
+/// Unknown scopes are ignored.
+///
+/// # Example
+/// ```rust
+/// use spotify_web_api::auth::scopes::{self, Scope};
+///
+/// let scopes = scopes::parse("user-read-email user-read-private");
+///
+/// assert!(scopes.contains(&Scope::UserReadEmail));
+/// assert!(scopes.contains(&Scope::UserReadPrivate));
+/// ```
+pub fn parse(s: &str) -> HashSet<Scope> {
+    s.split_whitespace()
+        .filter_map(|s| Scope::try_from(s).ok())
+        .collect()
+}
+
+/// The scopes an endpoint requires, as checked by clients before a request is sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequiredScopes {
+    /// The scopes that must all be granted (see [`Endpoint::required_scopes`]).
+    pub all: HashSet<Scope>,
+
+    /// The scopes of which at least one must be granted, if any (see [`Endpoint::any_of_scopes`]).
+    pub any_of: HashSet<Scope>,
+}
+
+impl RequiredScopes {
+    /// Returns the scopes required by an endpoint.
+    pub fn of<E>(endpoint: &E) -> Self
+    where
+        E: Endpoint + ?Sized,
+    {
+        Self {
+            all: endpoint.required_scopes(),
+            any_of: endpoint.any_of_scopes(),
+        }
+    }
+
+    /// Checks whether the `granted` scopes are enough: all of the `all` scopes, and at least
+    /// one of the `any_of` scopes if there are any.
+    pub fn is_satisfied_by(&self, granted: &HashSet<Scope>) -> bool {
+        self.all.is_subset(granted) && (self.any_of.is_empty() || !self.any_of.is_disjoint(granted))
+    }
+
+    /// Returns every scope listed in this set, whether all or only one of them is required.
+    pub fn to_set(&self) -> HashSet<Scope> {
+        self.all.union(&self.any_of).copied().collect()
+    }
+}
+
+/// Returns the union of the scopes required by a list of endpoints.
+///
+/// This is useful to build the set of scopes to request during authorization, based on the
+/// endpoints an application uses. All the scopes an endpoint accepts are included, including
+/// the ones of which it only needs one (see [`Endpoint::any_of_scopes`]).
+///
+/// # Example
+/// ```rust
+/// use spotify_web_api::{
+///     api::{Endpoint, player::GetPlaybackState, users::GetUserTopItems},
+///     auth::scopes::{self, Scope},
+///     model::TopItemType,
+/// };
+///
+/// let playback_state = GetPlaybackState::default();
+/// let top_items = GetUserTopItems::from(TopItemType::Artists);
+///
+/// let scopes = scopes::required_by([&playback_state as &dyn Endpoint, &top_items]);
+///
+/// assert!(scopes.contains(&Scope::UserReadPlaybackState));
+/// assert!(scopes.contains(&Scope::UserTopRead));
+/// ```
+pub fn required_by<'a, I>(endpoints: I) -> HashSet<Scope>
+where
+    I: IntoIterator<Item = &'a dyn Endpoint>,
+{
+    endpoints
+        .into_iter()
+        .flat_map(|endpoint| RequiredScopes::of(endpoint).to_set())
+        .collect()
+}
+
 impl std::fmt::Display for Scope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -376,6 +464,21 @@ impl From<Scope> for Option<HashSet<Scope>> {
     }
 }
 
+impl From<HashSet<Scope>> for RequiredScopes {
+    fn from(all: HashSet<Scope>) -> Self {
+        Self {
+            all,
+            any_of: HashSet::new(),
+        }
+    }
+}
+
+impl From<Scope> for RequiredScopes {
+    fn from(val: Scope) -> Self {
+        HashSet::from(val).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,4 +488,29 @@ mod tests {
         let scope = Scope::UserReadPlaybackState;
         assert_eq!("user-read-playback-state", scope.to_string());
     }
+
+    #[test]
+    fn required_scopes_any_of() {
+        let required = RequiredScopes {
+            all: Scope::UgcImageUpload.into(),
+            any_of: playlist_modify(),
+        };
+
+        assert!(required.is_satisfied_by(&HashSet::from([
+            Scope::UgcImageUpload,
+            Scope::PlaylistModifyPrivate
+        ])));
+        assert!(!required.is_satisfied_by(&Scope::PlaylistModifyPublic.into()));
+        assert!(!required.is_satisfied_by(&Scope::UgcImageUpload.into()));
+        assert!(RequiredScopes::default().is_satisfied_by(&HashSet::new()));
+    }
+
+    #[test]
+    fn scope_serde_matches_display() {
+        for scope in all() {
+            let json = serde_json::to_string(&scope).unwrap();
+            assert_eq!(json, format!("\"{scope}\""));
+            assert_eq!(serde_json::from_str::<Scope>(&json).unwrap(), scope);
+        }
+    }
 }
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use zeroize::Zeroize;
+
+/// A wrapper for sensitive values, such as access tokens and client secrets.
+///
+/// The wrapped value is redacted from the `Debug` and `Display` output, so that it does not
+/// leak into logs when a struct containing it is printed, and its memory is zeroed when it is
+/// dropped. It is serialized and deserialized as the plain value, so that tokens can still be
+/// persisted.
+///
+/// Use [`Secret::expose_secret`] to access the value.
+///
+/// # Example
+/// ```
+/// use spotify_web_api::auth::Secret;
+///
+/// let secret = Secret::from("hunter2");
+///
+/// assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+/// assert_eq!(secret.expose_secret(), "hunter2");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    /// Wraps a sensitive value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl From<String> for Secret<String> {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret<String> {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl<T: Zeroize + Serialize> Serialize for Secret<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Zeroize + Deserialize<'de>> Deserialize<'de> for Secret<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_is_redacted() {
+        let secret = Secret::from("hunter2");
+
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+        assert_eq!(secret.to_string(), "[REDACTED]");
+        assert_eq!(secret.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn secret_serializes_as_plain_value() {
+        let secret = Secret::from("hunter2");
+
+        let json = serde_json::to_string(&secret).unwrap();
+        assert_eq!(json, r#""hunter2""#);
+
+        let deserialized: Secret<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, secret);
+    }
+}
@@ -20,7 +20,7 @@
 //!
 //!     spotify.request_token()?;
 //!
-//!     let artist: Artist = GetArtist::from("0559tR6WyukLWH68JIGBuC").query(&spotify)?;
+//!     let artist: Artist = GetArtist::try_from("0559tR6WyukLWH68JIGBuC")?.query(&spotify)?;
 //!
 //!     println!("{artist:#?}");
 //!
@@ -111,12 +111,17 @@
 //!
 //! - `markets` - Enables the `available_markets` field in various models, such as [`model::Track`]. This field contains a list of markets where the content is available.
 //! - `page_items` - Enables the field in various models that contain paginated items, such as the `tracks` field in [`model::Playlist`].
+//!
+//! The following feature flags are **disabled by default**:
+//!
+//! - `callback_server` - Enables the [`auth::callback_server`] module, a local HTTP server that captures the authorization redirect for the PKCE flow.
 
 mod spotify;
 
 pub mod api;
 pub mod auth;
 pub mod model;
+pub mod session;
 pub use spotify::*;
 
 #[cfg(test)]
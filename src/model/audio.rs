@@ -0,0 +1,600 @@
+use super::ItemType;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A key, using standard [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    C,
+    CSharp,
+    D,
+    DSharp,
+    E,
+    F,
+    FSharp,
+    G,
+    GSharp,
+    A,
+    ASharp,
+    B,
+}
+
+impl Key {
+    /// Returns the key for a pitch class, from 0 (C) to 11 (B).
+    pub fn from_pitch_class(pitch_class: i32) -> Option<Self> {
+        let key = match pitch_class {
+            0 => Self::C,
+            1 => Self::CSharp,
+            2 => Self::D,
+            3 => Self::DSharp,
+            4 => Self::E,
+            5 => Self::F,
+            6 => Self::FSharp,
+            7 => Self::G,
+            8 => Self::GSharp,
+            9 => Self::A,
+            10 => Self::ASharp,
+            11 => Self::B,
+            _ => return None,
+        };
+
+        Some(key)
+    }
+
+    /// Returns the pitch class of the key, from 0 (C) to 11 (B).
+    pub fn pitch_class(self) -> i32 {
+        self as i32
+    }
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::C => "C",
+            Self::CSharp => "C♯/D♭",
+            Self::D => "D",
+            Self::DSharp => "D♯/E♭",
+            Self::E => "E",
+            Self::F => "F",
+            Self::FSharp => "F♯/G♭",
+            Self::G => "G",
+            Self::GSharp => "G♯/A♭",
+            Self::A => "A",
+            Self::ASharp => "A♯/B♭",
+            Self::B => "B",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+/// The modality (major or minor) of a track, the type of scale from which its melodic content is derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Minor,
+    Major,
+}
+
+impl Mode {
+    /// Returns the mode for a value, 0 (minor) or 1 (major).
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Minor),
+            1 => Some(Self::Major),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the mode, 0 (minor) or 1 (major).
+    pub fn value(self) -> i32 {
+        match self {
+            Self::Minor => 0,
+            Self::Major => 1,
+        }
+    }
+}
+
+/// (De)serializes an optional [`Key`] as its pitch class, using -1 when no key was detected.
+mod key {
+    use super::{Deserialize, Deserializer, Key, Serialize, Serializer};
+    use serde::de::{Error as _, Unexpected};
+
+    pub fn serialize<S: Serializer>(key: &Option<Key>, serializer: S) -> Result<S::Ok, S::Error> {
+        key.map_or(-1, Key::pitch_class).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Key>, D::Error> {
+        match i32::deserialize(deserializer)? {
+            -1 => Ok(None),
+            value => Key::from_pitch_class(value).map(Some).ok_or_else(|| {
+                D::Error::invalid_value(
+                    Unexpected::Signed(value.into()),
+                    &"a pitch class from 0 to 11, or -1",
+                )
+            }),
+        }
+    }
+}
+
+/// (De)serializes an optional [`Mode`] as 0 (minor) or 1 (major), using -1 when no mode was detected.
+mod mode {
+    use super::{Deserialize, Deserializer, Mode, Serialize, Serializer};
+    use serde::de::{Error as _, Unexpected};
+
+    pub fn serialize<S: Serializer>(mode: &Option<Mode>, serializer: S) -> Result<S::Ok, S::Error> {
+        mode.map_or(-1, Mode::value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Mode>, D::Error> {
+        match i32::deserialize(deserializer)? {
+            -1 => Ok(None),
+            value => Mode::from_value(value).map(Some).ok_or_else(|| {
+                D::Error::invalid_value(Unexpected::Signed(value.into()), &"0, 1, or -1")
+            }),
+        }
+    }
+}
+
+/// Audio features for a track.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioFeatures {
+    /// A confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    /// 1.0 represents high confidence the track is acoustic.
+    pub acousticness: f64,
+
+    /// A URL to access the full audio analysis of this track. An access token is required to access this data.
+    pub analysis_url: String,
+
+    /// Danceability describes how suitable a track is for dancing based on a combination of musical elements
+    /// including tempo, rhythm stability, beat strength, and overall regularity.
+    /// A value of 0.0 is least danceable and 1.0 is most danceable.
+    pub danceability: f64,
+
+    /// The duration of the track in milliseconds.
+    pub duration_ms: u32,
+
+    /// Energy is a measure from 0.0 to 1.0 and represents a perceptual measure of intensity and activity.
+    /// Typically, energetic tracks feel fast, loud, and noisy.
+    pub energy: f64,
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the track.
+    pub id: String,
+
+    /// Predicts whether a track contains no vocals.
+    /// The closer the instrumentalness value is to 1.0, the greater likelihood the track contains no vocal content.
+    pub instrumentalness: f64,
+
+    /// The key the track is in. None if no key was detected.
+    #[serde(with = "key")]
+    pub key: Option<Key>,
+
+    /// Detects the presence of an audience in the recording.
+    /// A value above 0.8 provides strong likelihood that the track is live.
+    pub liveness: f64,
+
+    /// The overall loudness of a track in decibels (dB), typically between -60 and 0 db.
+    pub loudness: f64,
+
+    /// The modality (major or minor) of the track. None if no mode was detected.
+    #[serde(with = "mode")]
+    pub mode: Option<Mode>,
+
+    /// Speechiness detects the presence of spoken words in a track.
+    /// The more exclusively speech-like the recording (e.g. talk show, audio book, poetry), the closer to 1.0 the attribute value.
+    pub speechiness: f64,
+
+    /// The overall estimated tempo of a track in beats per minute (BPM).
+    pub tempo: f64,
+
+    /// An estimated time signature, from 3 to 7, indicating how many beats are in each bar (or measure).
+    pub time_signature: i32,
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub track_href: String,
+
+    /// The object type: `audio_features`
+    #[serde(rename = "type")]
+    pub type_: ItemType,
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the track.
+    pub uri: String,
+
+    /// A measure from 0.0 to 1.0 describing the musical positiveness conveyed by a track.
+    /// Tracks with high valence sound more positive (e.g. happy, cheerful, euphoric),
+    /// while tracks with low valence sound more negative (e.g. sad, depressed, angry).
+    pub valence: f64,
+}
+
+/// Audio features for several tracks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioFeaturesList {
+    /// The audio features, in the order of the requested IDs. None for IDs without audio features.
+    pub audio_features: Vec<Option<AudioFeatures>>,
+}
+
+/// A low-level audio analysis for a track, describing its structure and musical content,
+/// including rhythm, pitch, and timbre.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioAnalysis {
+    /// Information about the analysis itself.
+    pub meta: AudioAnalysisMeta,
+
+    /// Global values for the whole track.
+    pub track: AudioAnalysisTrack,
+
+    /// The time intervals of the bars throughout the track.
+    /// A bar (or measure) is a segment of time defined as a given number of beats.
+    pub bars: Vec<TimeInterval>,
+
+    /// The time intervals of beats throughout the track.
+    /// A beat is the basic time unit of a piece of music; for example, each tick of a metronome.
+    pub beats: Vec<TimeInterval>,
+
+    /// Sections are defined by large variations in rhythm or timbre, e.g. chorus, verse, bridge, guitar solo, etc.
+    pub sections: Vec<Section>,
+
+    /// Each segment contains a roughly consistent sound throughout its duration.
+    pub segments: Vec<Segment>,
+
+    /// A tatum represents the lowest regular pulse train that a listener intuitively infers from the timing of perceived musical events (segments).
+    pub tatums: Vec<TimeInterval>,
+}
+
+/// Information about an audio analysis, such as the analyzer version and status.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioAnalysisMeta {
+    /// The version of the Analyzer used to analyze this track.
+    pub analyzer_version: String,
+
+    /// The platform used to read the track's audio data.
+    pub platform: String,
+
+    /// A detailed status code for this track.
+    /// If analysis data is missing, this code may explain why.
+    pub detailed_status: String,
+
+    /// The return code of the analyzer process. 0 if successful, 1 if any errors occurred.
+    pub status_code: i32,
+
+    /// The Unix timestamp (in seconds) at which this track was analyzed.
+    pub timestamp: i64,
+
+    /// The amount of time taken to analyze this track.
+    pub analysis_time: f64,
+
+    /// The method used to read the track's audio data.
+    pub input_process: String,
+}
+
+/// Global values of an audio analysis for the whole track.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioAnalysisTrack {
+    /// The exact number of audio samples analyzed from this track.
+    pub num_samples: u64,
+
+    /// Length of the track in seconds.
+    pub duration: f64,
+
+    /// This field will always contain the empty string.
+    pub sample_md5: String,
+
+    /// An offset to the start of the region of the track that was analyzed.
+    pub offset_seconds: f64,
+
+    /// The length of the region of the track was analyzed, if a subset of the track was analyzed.
+    pub window_seconds: f64,
+
+    /// The sample rate used to decode and analyze this track.
+    pub analysis_sample_rate: u32,
+
+    /// The number of channels used for analysis. If 1, all channels are summed together to mono before analysis.
+    pub analysis_channels: u32,
+
+    /// The time, in seconds, at which the track's fade-in period ends. If the track has no fade-in, this will be 0.0.
+    pub end_of_fade_in: f64,
+
+    /// The time, in seconds, at which the track's fade-out period starts.
+    /// If the track has no fade-out, this should match the track's length.
+    pub start_of_fade_out: f64,
+
+    /// The overall loudness of a track in decibels (dB).
+    pub loudness: f64,
+
+    /// The overall estimated tempo of a track in beats per minute (BPM).
+    pub tempo: f64,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the tempo.
+    pub tempo_confidence: f64,
+
+    /// An estimated time signature, from 3 to 7, indicating how many beats are in each bar (or measure).
+    pub time_signature: i32,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the time signature.
+    pub time_signature_confidence: f64,
+
+    /// The key the track is in. None if no key was detected.
+    #[serde(with = "key")]
+    pub key: Option<Key>,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the key.
+    pub key_confidence: f64,
+
+    /// The modality (major or minor) of the track. None if no mode was detected.
+    #[serde(with = "mode")]
+    pub mode: Option<Mode>,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the mode.
+    pub mode_confidence: f64,
+
+    /// An [Echo Nest Musical Fingerprint (ENMFP)](https://academiccommons.columbia.edu/doi/10.7916/D8Q248M4) codestring for this track.
+    pub codestring: String,
+
+    /// A version number for the Echo Nest Musical Fingerprint format used in the codestring field.
+    pub code_version: f64,
+
+    /// An [EchoPrint](https://github.com/spotify/echoprint-codegen) codestring for this track.
+    pub echoprintstring: String,
+
+    /// A version number for the `EchoPrint` format used in the `echoprintstring` field.
+    pub echoprint_version: f64,
+
+    /// A [Synchstring](https://github.com/echonest/synchdata) for this track.
+    pub synchstring: String,
+
+    /// A version number for the Synchstring used in the synchstring field.
+    pub synch_version: f64,
+
+    /// A Rhythmstring for this track. The format of this string is similar to the Synchstring.
+    pub rhythmstring: String,
+
+    /// A version number for the Rhythmstring used in the rhythmstring field.
+    pub rhythm_version: f64,
+}
+
+/// A time interval of a bar, beat or tatum.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeInterval {
+    /// The starting point (in seconds) of the time interval.
+    pub start: f64,
+
+    /// The duration (in seconds) of the time interval.
+    pub duration: f64,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the interval.
+    pub confidence: f64,
+}
+
+/// A section of a track, defined by large variations in rhythm or timbre.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Section {
+    /// The starting point (in seconds) of the section.
+    pub start: f64,
+
+    /// The duration (in seconds) of the section.
+    pub duration: f64,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the section's "designation".
+    pub confidence: f64,
+
+    /// The overall loudness of the section in decibels (dB).
+    pub loudness: f64,
+
+    /// The overall estimated tempo of the section in beats per minute (BPM).
+    pub tempo: f64,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the tempo.
+    pub tempo_confidence: f64,
+
+    /// The estimated overall key of the section. None if no key was detected.
+    #[serde(with = "key")]
+    pub key: Option<Key>,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the key.
+    pub key_confidence: f64,
+
+    /// The modality (major or minor) of the section. None if no mode was detected.
+    #[serde(with = "mode")]
+    pub mode: Option<Mode>,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the mode.
+    pub mode_confidence: f64,
+
+    /// An estimated time signature, from 3 to 7, indicating how many beats are in each bar (or measure).
+    pub time_signature: i32,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the time signature.
+    pub time_signature_confidence: f64,
+}
+
+/// A segment of a track, with a roughly consistent sound throughout its duration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Segment {
+    /// The starting point (in seconds) of the segment.
+    pub start: f64,
+
+    /// The duration (in seconds) of the segment.
+    pub duration: f64,
+
+    /// The confidence, from 0.0 to 1.0, of the reliability of the segmentation.
+    pub confidence: f64,
+
+    /// The onset loudness of the segment in decibels (dB).
+    pub loudness_start: f64,
+
+    /// The peak loudness of the segment in decibels (dB).
+    pub loudness_max: f64,
+
+    /// The segment-relative offset of the segment peak loudness in seconds.
+    pub loudness_max_time: f64,
+
+    /// The offset loudness of the segment in decibels (dB).
+    /// This value should be equivalent to the `loudness_start` of the following segment.
+    pub loudness_end: f64,
+
+    /// Pitch content is given by a “chroma” vector, corresponding to the 12 pitch classes C, C#, D to B,
+    /// with values ranging from 0 to 1 that describe the relative dominance of every pitch in the chromatic scale.
+    pub pitches: Vec<f64>,
+
+    /// Timbre is the quality of a musical note or sound that distinguishes different types of musical instruments, or voices.
+    /// It is represented by a vector of 12 unbounded values roughly centered around 0.
+    pub timbre: Vec<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_features() {
+        let json = r#"
+        {
+			"acousticness": 0.00242,
+			"analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+			"danceability": 0.585,
+			"duration_ms": 237040,
+			"energy": 0.842,
+			"id": "2takcwOaAZWiXQijPHIx7B",
+			"instrumentalness": 0.00686,
+			"key": 9,
+			"liveness": 0.0866,
+			"loudness": -5.883,
+			"mode": 0,
+			"speechiness": 0.0556,
+			"tempo": 118.211,
+			"time_signature": 4,
+			"track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+			"type": "audio_features",
+			"uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+			"valence": 0.428
+        }
+        "#;
+
+        let features: AudioFeatures = serde_json::from_str(json).unwrap();
+
+        assert_eq!(features.key, Some(Key::A));
+        assert_eq!(features.mode, Some(Mode::Minor));
+        assert_eq!(features.type_, ItemType::AudioFeatures);
+
+        let value = serde_json::to_value(&features).unwrap();
+        assert_eq!(value["key"], 9);
+        assert_eq!(value["mode"], 0);
+    }
+
+    #[test]
+    fn audio_analysis() {
+        let json = r#"
+        {
+			"meta": {
+				"analyzer_version": "4.0.0",
+				"platform": "Linux",
+				"detailed_status": "OK",
+				"status_code": 0,
+				"timestamp": 1495193577,
+				"analysis_time": 6.93906,
+				"input_process": "libvorbisfile L+R 44100->22050"
+			},
+			"track": {
+				"num_samples": 4585515,
+				"duration": 207.95985,
+				"sample_md5": "string",
+				"offset_seconds": 0,
+				"window_seconds": 0,
+				"analysis_sample_rate": 22050,
+				"analysis_channels": 1,
+				"end_of_fade_in": 0,
+				"start_of_fade_out": 201.13705,
+				"loudness": -5.883,
+				"tempo": 118.211,
+				"tempo_confidence": 0.73,
+				"time_signature": 4,
+				"time_signature_confidence": 0.994,
+				"key": -1,
+				"key_confidence": 0.408,
+				"mode": 0,
+				"mode_confidence": 0.485,
+				"codestring": "string",
+				"code_version": 3.15,
+				"echoprintstring": "string",
+				"echoprint_version": 4.15,
+				"synchstring": "string",
+				"synch_version": 1,
+				"rhythmstring": "string",
+				"rhythm_version": 1
+			},
+			"bars": [
+				{
+					"start": 0.49567,
+					"duration": 2.18749,
+					"confidence": 0.925
+				}
+			],
+			"beats": [
+				{
+					"start": 0.49567,
+					"duration": 2.18749,
+					"confidence": 0.925
+				}
+			],
+			"sections": [
+				{
+					"start": 0,
+					"duration": 6.97092,
+					"confidence": 1,
+					"loudness": -14.938,
+					"tempo": 113.178,
+					"tempo_confidence": 0.647,
+					"key": 9,
+					"key_confidence": 0.297,
+					"mode": -1,
+					"mode_confidence": 0.471,
+					"time_signature": 4,
+					"time_signature_confidence": 1
+				}
+			],
+			"segments": [
+				{
+					"start": 0.70154,
+					"duration": 0.19891,
+					"confidence": 0.435,
+					"loudness_start": -23.053,
+					"loudness_max": -14.25,
+					"loudness_max_time": 0.07305,
+					"loudness_end": 0,
+					"pitches": [0.212, 0.141, 0.294],
+					"timbre": [42.115, 64.373, -0.233]
+				}
+			],
+			"tatums": [
+				{
+					"start": 0.49567,
+					"duration": 2.18749,
+					"confidence": 0.925
+				}
+			]
+        }
+        "#;
+
+        let analysis: AudioAnalysis = serde_json::from_str(json).unwrap();
+
+        assert_eq!(analysis.track.key, None);
+        assert_eq!(analysis.sections[0].key, Some(Key::A));
+        assert_eq!(analysis.sections[0].mode, None);
+    }
+
+    #[test]
+    fn key_and_mode_out_of_range() {
+        use serde_json::json;
+
+        assert_eq!(key::deserialize(json!(-1)).unwrap(), None);
+        assert_eq!(key::deserialize(json!(11)).unwrap(), Some(Key::B));
+        assert!(key::deserialize(json!(12)).is_err());
+        assert!(key::deserialize(json!(-2)).is_err());
+
+        assert_eq!(mode::deserialize(json!(-1)).unwrap(), None);
+        assert_eq!(mode::deserialize(json!(1)).unwrap(), Some(Mode::Major));
+        assert!(mode::deserialize(json!(2)).is_err());
+    }
+}
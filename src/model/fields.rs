@@ -0,0 +1,130 @@
+/// A selection of fields to return, written in Spotify's `fields` mini-language.
+///
+/// Fields are comma-separated, nested selections are wrapped in parentheses,
+/// dots select a single nested field and a leading `!` excludes a field.
+///
+/// # Example
+/// ```
+/// use spotify_web_api::model::Fields;
+///
+/// let fields = Fields::new()
+///     .field("next")
+///     .nested(
+///         "items",
+///         Fields::new()
+///             .field("added_by.id")
+///             .nested("track", Fields::new().field("name").exclude("album")),
+///     );
+///
+/// assert_eq!(fields.to_string(), "next,items(added_by.id,track(name,!album))");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fields {
+    selections: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selection {
+    Field(String),
+    Nested(String, Fields),
+    Exclude(String),
+}
+
+impl Fields {
+    /// The fields of a [`Page`](super::Page), which the paged queries need in every response.
+    const PAGE_FIELDS: [&'static str; 6] = ["href", "limit", "next", "offset", "previous", "total"];
+
+    /// Creates an empty selection, to which fields are added with the methods below.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the fields a [`Page`](super::Page) is made of, with `items` narrowed to `items`.
+    ///
+    /// Use this when querying a paged endpoint, such as `GetPlaylistItems`, through
+    /// [`api::paged`](crate::api::paged), since each response must still deserialize into a page.
+    pub fn page(items: Self) -> Self {
+        Self::PAGE_FIELDS
+            .into_iter()
+            .fold(Self::new(), Self::field)
+            .nested("items", items)
+    }
+
+    /// Selects a field. Dots may be used to select a single nested field, e.g. `added_by.id`.
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.selections.push(Selection::Field(name.into()));
+        self
+    }
+
+    /// Selects the given fields of a nested object.
+    pub fn nested(mut self, name: impl Into<String>, fields: Self) -> Self {
+        self.selections.push(Selection::Nested(name.into(), fields));
+        self
+    }
+
+    /// Excludes a field from the response.
+    pub fn exclude(mut self, name: impl Into<String>) -> Self {
+        self.selections.push(Selection::Exclude(name.into()));
+        self
+    }
+
+    /// Returns `true` if no field is selected or excluded.
+    pub fn is_empty(&self) -> bool {
+        self.selections.is_empty()
+    }
+}
+
+impl std::fmt::Display for Fields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, selection) in self.selections.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+
+            match selection {
+                Selection::Field(name) => write!(f, "{name}")?,
+                Selection::Nested(name, fields) => write!(f, "{name}({fields})")?,
+                Selection::Exclude(name) => write!(f, "!{name}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_to_string() {
+        let fields = Fields::new().field("description").field("uri").nested(
+            "tracks",
+            Fields::new().nested(
+                "items",
+                Fields::new().nested(
+                    "track",
+                    Fields::new()
+                        .field("name")
+                        .nested("album", Fields::new().exclude("name").field("href")),
+                ),
+            ),
+        );
+
+        assert_eq!(
+            fields.to_string(),
+            "description,uri,tracks(items(track(name,album(!name,href))))"
+        );
+    }
+
+    #[test]
+    fn page_fields_to_string() {
+        let fields = Fields::page(Fields::new().field("track.name"));
+
+        assert_eq!(
+            fields.to_string(),
+            "href,limit,next,offset,previous,total,items(track.name)"
+        );
+        assert!(Fields::new().is_empty());
+    }
+}
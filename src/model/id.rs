@@ -1,15 +1,40 @@
 use thiserror::Error;
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum IdError {
     #[error("The ID is not in correct format.")]
     InvalidFormat,
 
     #[error("The ID is not the correct length. Got {got}, expected {expected}.")]
     InvalidLength { got: usize, expected: usize },
+
+    #[error("The input is not a Spotify URI or URL.")]
+    InvalidUri,
+
+    #[error("Expected a {expected} ID, got a {got} ID.")]
+    UnexpectedType { expected: IdType, got: IdType },
+
+    #[error("Expected an ID of type {}, got a {got} ID.", join_types(accepted))]
+    UnsupportedType {
+        accepted: &'static [IdType],
+        got: IdType,
+    },
+
+    #[error("Got {count} IDs, at most {max} are accepted.")]
+    TooManyIds { count: usize, max: usize },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+fn join_types(types: &[IdType]) -> String {
+    types
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum IdType {
     User,
     Album,
@@ -18,6 +43,8 @@ pub enum IdType {
     Track,
     Show,
     Episode,
+    Audiobook,
+    Chapter,
 }
 
 impl std::fmt::Display for IdType {
@@ -30,6 +57,8 @@ impl std::fmt::Display for IdType {
             Self::Track => "track",
             Self::Show => "show",
             Self::Episode => "episode",
+            Self::Audiobook => "audiobook",
+            Self::Chapter => "chapter",
         };
         write!(f, "{s}")
     }
@@ -44,6 +73,14 @@ pub enum ContextType {
 }
 
 impl ContextType {
+    /// The types of the IDs a context can be made of.
+    pub const ID_TYPES: &'static [IdType] = &[
+        IdType::Album,
+        IdType::Artist,
+        IdType::Playlist,
+        IdType::Show,
+    ];
+
     pub fn uri(&self) -> String {
         match self {
             Self::Album(id) => id.uri(),
@@ -142,6 +179,28 @@ macro_rules! impl_ids {
         			format!("spotify:{}:{}", self._type(), self.id())
     			}
             }
+
+            impl TryFrom<&str> for $struct_name {
+                type Error = IdError;
+
+                fn try_from(id: &str) -> Result<Self, Self::Error> {
+                    Self::from_id(id)
+                }
+            }
+
+            impl TryFrom<String> for $struct_name {
+                type Error = IdError;
+
+                fn try_from(id: String) -> Result<Self, Self::Error> {
+                    Self::from_id(id)
+                }
+            }
+
+            impl AsRef<str> for $struct_name {
+                fn as_ref(&self) -> &str {
+                    self.id()
+                }
+            }
         )*
     }
 }
@@ -154,8 +213,19 @@ impl_ids![
     (ShowId, Show, "show"),
     (EpisodeId, Episode, "episode"),
     (UserId, User, "user"),
+    (AudiobookId, Audiobook, "audiobook"),
+    (ChapterId, Chapter, "chapter"),
 ];
 
+/// Parses a list of Spotify IDs of the same type, failing on the first invalid one.
+pub(crate) fn parse_ids<T, S>(ids: impl IntoIterator<Item = S>) -> Result<Vec<T>, IdError>
+where
+    T: for<'a> TryFrom<&'a str, Error = IdError>,
+    S: AsRef<str>,
+{
+    ids.into_iter().map(|id| T::try_from(id.as_ref())).collect()
+}
+
 #[inline(always)]
 fn is_base62(s: &str) -> bool {
     s.chars().all(|c| c.is_ascii_alphanumeric())
@@ -182,6 +252,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_ids() {
+        let ids = parse_ids::<TrackId, _>(["6rqhFgbbKwnb9MLmUQDhG6", "4VqPOruhp5EdPBeR92t6lQ"]);
+        assert_eq!(ids.unwrap().len(), 2);
+
+        let ids = parse_ids::<TrackId, _>(["6rqhFgbbKwnb9MLmUQDhG6", "6rqhFgbbKwnb9MLmUQDhG-"]);
+        assert_eq!(ids, Err(IdError::InvalidFormat));
+    }
+
     #[test]
     fn test_id_from_uri() {
         let uri = "spotify:track:6rqhFgbbKwnb9MLmUQDhG6";
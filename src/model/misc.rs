@@ -14,6 +14,8 @@ pub enum ItemType {
     Unknown,
     Chapter,
     Collection,
+    #[serde(rename = "audio_features")]
+    AudioFeatures,
 }
 
 impl std::fmt::Display for ItemType {
@@ -30,6 +32,7 @@ impl std::fmt::Display for ItemType {
             Self::Unknown => "unknown",
             Self::Chapter => "chapter",
             Self::Collection => "collection",
+            Self::AudioFeatures => "audio_features",
         };
 
         write!(f, "{s}")
@@ -342,3 +345,22 @@ impl std::fmt::Display for IncludeExternalType {
         write!(f, "{s}")
     }
 }
+
+/// An item type, besides the default `track`, that the client supports in playlist and player responses.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AdditionalType {
+    Track,
+    Episode,
+}
+
+impl std::fmt::Display for AdditionalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Track => "track",
+            Self::Episode => "episode",
+        };
+
+        write!(f, "{s}")
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ContextType, Cursors, EpisodeId, ExternalUrls, ItemType, Track, TrackId, TrackItem};
+use super::{Cursors, EpisodeId, ExternalUrls, IdType, ItemType, Track, TrackId, TrackItem};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -222,8 +222,11 @@ pub struct Queue {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Offset {
+    /// The zero-based position of the item in the context.
     Position(usize),
-    Uri(ContextType),
+
+    /// The track or episode in the context to start from.
+    Uri(PlaylistItem),
 }
 
 impl From<usize> for Offset {
@@ -232,6 +235,24 @@ impl From<usize> for Offset {
     }
 }
 
+impl From<PlaylistItem> for Offset {
+    fn from(item: PlaylistItem) -> Self {
+        Self::Uri(item)
+    }
+}
+
+impl From<TrackId> for Offset {
+    fn from(track: TrackId) -> Self {
+        Self::Uri(track.into())
+    }
+}
+
+impl From<EpisodeId> for Offset {
+    fn from(episode: EpisodeId) -> Self {
+        Self::Uri(episode.into())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QueryRange {
     Before(i64),
@@ -244,6 +265,11 @@ pub enum PlaylistItem {
     Episode(EpisodeId),
 }
 
+impl PlaylistItem {
+    /// The types of the IDs a playlist item can be made of.
+    pub const ID_TYPES: &'static [IdType] = &[IdType::Track, IdType::Episode];
+}
+
 impl From<TrackId> for PlaylistItem {
     fn from(track: TrackId) -> Self {
         Self::Track(track)
@@ -405,4 +431,76 @@ mod tests {
 
         crate::test::assert_deserialized!(PlaybackState, json);
     }
+
+    #[test]
+    fn currently_playing_episode() {
+        let json = r#"
+        {
+            "context": null,
+            "timestamp": 1729000000000,
+            "progress_ms": 61000,
+            "is_playing": true,
+            "item": {
+                "audio_preview_url": null,
+                "description": "A description of the episode.",
+                "html_description": "<p>A description of the episode.</p>",
+                "duration_ms": 1686230,
+                "explicit": false,
+                "external_urls": {
+                    "spotify": "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ"
+                },
+                "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+                "id": "512ojhOuo1ktJprKbVcKyQ",
+                "images": [],
+                "is_externally_hosted": false,
+                "is_playable": true,
+                "language": "en",
+                "languages": ["en"],
+                "name": "An episode",
+                "release_date": "2024-10-15",
+                "release_date_precision": "day",
+                "show": {
+                    "available_markets": ["US"],
+                    "copyrights": [],
+                    "description": "A description of the show.",
+                    "html_description": "<p>A description of the show.</p>",
+                    "explicit": false,
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ"
+                    },
+                    "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+                    "id": "38bS44xjbVVZ3No3ByF1dJ",
+                    "images": [],
+                    "is_externally_hosted": false,
+                    "languages": ["en"],
+                    "media_type": "audio",
+                    "name": "A show",
+                    "publisher": "A publisher",
+                    "type": "show",
+                    "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ",
+                    "total_episodes": 100
+                },
+                "type": "episode",
+                "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+            },
+            "currently_playing_type": "episode",
+            "actions": {
+                "disallows": {
+                    "resuming": true
+                }
+            }
+        }
+        "#;
+
+        let currently_playing: CurrentlyPlaying = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            currently_playing.currently_playing_type,
+            CurrentlyPlayingType::Episode
+        );
+        assert!(matches!(
+            &currently_playing.item,
+            Some(TrackItem::Episode(episode)) if episode.id == "512ojhOuo1ktJprKbVcKyQ"
+        ));
+    }
 }
@@ -188,6 +188,16 @@ pub struct AddedBy {
     pub uri: String,
 }
 
+/// A list of playlists featured in Spotify, or tagged with a category, along with a localized message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeaturedPlaylists {
+    /// A localized message describing the playlists, for example "Popular Playlists".
+    pub message: String,
+
+    /// The playlists.
+    pub playlists: Page<SimplifiedPlaylist>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SnapshotId {
     /// The version identifier for the current playlist.
@@ -0,0 +1,74 @@
+use super::Track;
+use serde::{Deserialize, Serialize};
+
+/// Recommendations generated from a set of seeds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Recommendations {
+    /// The seeds the recommendations were generated from.
+    pub seeds: Vec<RecommendationSeed>,
+
+    /// The recommended tracks, ordered by relevance.
+    pub tracks: Vec<Track>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecommendationSeed {
+    /// The number of tracks available after min_* and max_* filters have been applied.
+    #[serde(rename = "afterFilteringSize")]
+    pub after_filtering_size: u32,
+
+    /// The number of tracks available after relinking for regional availability.
+    #[serde(rename = "afterRelinkingSize")]
+    pub after_relinking_size: u32,
+
+    /// A link to the full track or artist data for this seed.
+    /// For tracks this will be a link to a Track Object. For artists a link to an Artist Object.
+    /// For genre seeds, this value will be None.
+    pub href: Option<String>,
+
+    /// The id used to select this seed. This will be the same as the string used in the
+    /// `seed_artists`, `seed_tracks` or `seed_genres` parameter.
+    pub id: String,
+
+    /// The number of recommended tracks available for this seed.
+    #[serde(rename = "initialPoolSize")]
+    pub initial_pool_size: u32,
+
+    /// The entity type of this seed.
+    #[serde(rename = "type")]
+    pub type_: RecommendationSeedType,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecommendationSeedType {
+    #[serde(alias = "ARTIST")]
+    Artist,
+    #[serde(alias = "TRACK")]
+    Track,
+    #[serde(alias = "GENRE")]
+    Genre,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommendation_seed() {
+        let json = r#"
+        {
+			"afterFilteringSize": 250,
+			"afterRelinkingSize": 250,
+			"href": null,
+			"id": "classical",
+			"initialPoolSize": 250,
+			"type": "GENRE"
+        }
+        "#;
+
+        let seed: RecommendationSeed = serde_json::from_str(json).unwrap();
+
+        assert_eq!(seed.type_, RecommendationSeedType::Genre);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::auth::Secret;
 use chrono::{DateTime, TimeDelta, Utc, serde::ts_seconds_option};
 use serde::{Deserialize, Serialize};
 
@@ -11,20 +12,21 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Fields
 /// - `access_token`: The access token itself, which is used to authenticate
-///   requests to the API.
+///   requests to the API. It is redacted from the `Debug` output.
 /// - `token_type`: The type of token, usually "Bearer".
 /// - `expires_in`: The time period (in seconds) for which the access token is valid.
 /// - `expires_at`: An optional field that stores the expiration time of the token,
 ///   used to check if the token has expired. If not provided, the expiration time
 ///   is inferred from `expires_in`.
 /// - `refresh_token`: An optional field that contains a refresh token used to
-///   obtain new access tokens without user reauthorization.
+///   obtain new access tokens without user reauthorization. It is redacted from
+///   the `Debug` output.
 /// - `scope`: An optional field that contains a list of scopes granted to the
 ///   `access_token`.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Token {
     /// An access token that can be provided in subsequent calls, for example to Spotify Web API services.
-    pub access_token: String,
+    pub access_token: Secret<String>,
 
     /// How the access token may be used: always "Bearer".
     pub token_type: String,
@@ -42,7 +44,7 @@ pub struct Token {
 
     /// Security credential that allows client applications to obtain new access tokens without requiring users to reauthorize the application.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<Secret<String>>,
 
     /// A space-separated list of scopes which have been granted for this `access_token`.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,6 +66,27 @@ impl Token {
         self.expires_at
             .is_none_or(|expires_at| Utc::now() + TimeDelta::seconds(10) >= expires_at)
     }
+
+    /// Merges a token obtained from a refresh request with the token it replaces.
+    ///
+    /// Spotify may omit the `refresh_token` and `scope` fields from a refresh response.
+    /// In that case the values from the `previous` token are kept, otherwise the new values
+    /// are adopted.
+    ///
+    /// # Returns
+    /// The refreshed token, completed with the fields missing from the response.
+    #[must_use]
+    pub fn merge_refreshed(mut self, previous: &Self) -> Self {
+        if self.refresh_token.is_none() {
+            self.refresh_token.clone_from(&previous.refresh_token);
+        }
+
+        if self.scope.is_none() {
+            self.scope.clone_from(&previous.scope);
+        }
+
+        self
+    }
 }
 
 #[cfg(test)]
@@ -84,4 +107,67 @@ mod tests {
 
         crate::test::assert_deserialized!(Token, json);
     }
+
+    #[test]
+    fn token_debug_redacts_secrets() {
+        let token = Token {
+            access_token: "access-secret".into(),
+            refresh_token: Some("refresh-secret".into()),
+            ..Default::default()
+        };
+
+        let debug = format!("{token:?}");
+
+        assert!(!debug.contains("access-secret"));
+        assert!(!debug.contains("refresh-secret"));
+
+        let json = serde_json::to_string(&token).unwrap();
+        let deserialized: Token = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.access_token.expose_secret(), "access-secret");
+    }
+
+    #[test]
+    fn merge_refreshed_keeps_missing_fields() {
+        let previous = Token {
+            access_token: "old".into(),
+            refresh_token: Some("refresh".into()),
+            scope: Some("user-read-email".to_owned()),
+            ..Default::default()
+        };
+
+        let refreshed = Token {
+            access_token: "new".into(),
+            ..Default::default()
+        }
+        .merge_refreshed(&previous);
+
+        assert_eq!(refreshed.access_token.expose_secret(), "new");
+        assert_eq!(
+            refreshed.refresh_token.as_ref().map(Secret::expose_secret),
+            Some(&"refresh".to_owned())
+        );
+        assert_eq!(refreshed.scope.as_deref(), Some("user-read-email"));
+    }
+
+    #[test]
+    fn merge_refreshed_adopts_rotated_fields() {
+        let previous = Token {
+            refresh_token: Some("old-refresh".into()),
+            scope: Some("user-read-email".to_owned()),
+            ..Default::default()
+        };
+
+        let refreshed = Token {
+            refresh_token: Some("new-refresh".into()),
+            scope: Some("user-read-private".to_owned()),
+            ..Default::default()
+        }
+        .merge_refreshed(&previous);
+
+        assert_eq!(
+            refreshed.refresh_token.as_ref().map(Secret::expose_secret),
+            Some(&"new-refresh".to_owned())
+        );
+        assert_eq!(refreshed.scope.as_deref(), Some("user-read-private"));
+    }
 }
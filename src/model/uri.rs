@@ -0,0 +1,384 @@
+use super::{
+    AlbumId, ArtistId, AudiobookId, ChapterId, ContextType, EpisodeId, IdError, IdType, PlaylistId,
+    PlaylistItem, ShowId, TrackId, UserId,
+};
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+/// The hosts of Spotify web player and embed links.
+const WEB_HOSTS: [&str; 3] = ["open.spotify.com", "play.spotify.com", "embed.spotify.com"];
+
+/// A reference to any Spotify resource, parsed from a URI or a link.
+///
+/// The following forms are accepted:
+/// - URIs, e.g. `spotify:track:6rqhFgbbKwnb9MLmUQDhG6`.
+/// - Legacy playlist URIs, e.g. `spotify:user:spotify:playlist:37i9dQZF1DXcBWIGoYBM5M`.
+/// - Web player links, with or without a localized `intl-` prefix and query string,
+///   e.g. `https://open.spotify.com/intl-de/track/6rqhFgbbKwnb9MLmUQDhG6?si=1a2b3c`.
+/// - Embed links, e.g. `https://open.spotify.com/embed/playlist/37i9dQZF1DXcBWIGoYBM5M`.
+///
+/// [`Display`](std::fmt::Display) writes the canonical URI, or the `open.spotify.com` link with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use spotify_web_api::model::{SpotifyUri, TrackId};
+///
+/// let uri: SpotifyUri = "https://open.spotify.com/intl-de/track/6rqhFgbbKwnb9MLmUQDhG6?si=1a2b3c".parse()?;
+///
+/// assert_eq!(uri.to_string(), "spotify:track:6rqhFgbbKwnb9MLmUQDhG6");
+/// assert_eq!(format!("{uri:#}"), "https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6");
+///
+/// let track_id = TrackId::try_from(uri)?;
+/// assert_eq!(track_id.id(), "6rqhFgbbKwnb9MLmUQDhG6");
+/// # Ok::<(), spotify_web_api::model::IdError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpotifyUri {
+    Album(AlbumId),
+    Artist(ArtistId),
+    Audiobook(AudiobookId),
+    Chapter(ChapterId),
+    Episode(EpisodeId),
+    Playlist(PlaylistId),
+    Show(ShowId),
+    Track(TrackId),
+    User(UserId),
+}
+
+impl SpotifyUri {
+    /// Parses a Spotify URI or link.
+    pub fn parse(input: &str) -> Result<Self, IdError> {
+        let input = input.trim();
+
+        if let Some(path) = input.strip_prefix("spotify:") {
+            return Self::from_segments(path.split(':'));
+        }
+
+        let url = if input.contains("://") {
+            Url::parse(input)
+        } else {
+            Url::parse(&format!("https://{input}"))
+        }
+        .map_err(|_e| IdError::InvalidUri)?;
+
+        if !url.host_str().is_some_and(|host| WEB_HOSTS.contains(&host)) {
+            return Err(IdError::InvalidUri);
+        }
+
+        let mut segments = url
+            .path_segments()
+            .ok_or(IdError::InvalidUri)?
+            .filter(|segment| !segment.is_empty())
+            .peekable();
+
+        if segments
+            .peek()
+            .is_some_and(|segment| segment.starts_with("intl-"))
+        {
+            segments.next();
+        }
+
+        if segments
+            .peek()
+            .is_some_and(|segment| segment.starts_with("embed"))
+        {
+            segments.next();
+        }
+
+        let segments = segments
+            .map(|segment| percent_decode_str(segment).decode_utf8())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_e| IdError::InvalidUri)?;
+
+        Self::from_segments(segments.iter().map(AsRef::as_ref))
+    }
+
+    /// Parses the segments following `spotify:` in a URI, or the path of a link.
+    fn from_segments<'a>(segments: impl IntoIterator<Item = &'a str>) -> Result<Self, IdError> {
+        let segments: Vec<_> = segments.into_iter().collect();
+
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(IdError::InvalidUri);
+        }
+
+        match segments.as_slice() {
+            ["user", _, "playlist", id] | ["playlist", id] => {
+                PlaylistId::from_id(*id).map(Self::from)
+            }
+            ["user", id] => UserId::from_id(*id).map(Self::from),
+            ["album", id] => AlbumId::from_id(*id).map(Self::from),
+            ["artist", id] => ArtistId::from_id(*id).map(Self::from),
+            ["audiobook", id] => AudiobookId::from_id(*id).map(Self::from),
+            ["chapter", id] => ChapterId::from_id(*id).map(Self::from),
+            ["episode", id] => EpisodeId::from_id(*id).map(Self::from),
+            ["show", id] => ShowId::from_id(*id).map(Self::from),
+            ["track", id] => TrackId::from_id(*id).map(Self::from),
+            _ => Err(IdError::InvalidUri),
+        }
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the resource.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Album(id) => id.id(),
+            Self::Artist(id) => id.id(),
+            Self::Audiobook(id) => id.id(),
+            Self::Chapter(id) => id.id(),
+            Self::Episode(id) => id.id(),
+            Self::Playlist(id) => id.id(),
+            Self::Show(id) => id.id(),
+            Self::Track(id) => id.id(),
+            Self::User(id) => id.id(),
+        }
+    }
+
+    /// The type of the resource.
+    pub fn id_type(&self) -> IdType {
+        match self {
+            Self::Album(_) => IdType::Album,
+            Self::Artist(_) => IdType::Artist,
+            Self::Audiobook(_) => IdType::Audiobook,
+            Self::Chapter(_) => IdType::Chapter,
+            Self::Episode(_) => IdType::Episode,
+            Self::Playlist(_) => IdType::Playlist,
+            Self::Show(_) => IdType::Show,
+            Self::Track(_) => IdType::Track,
+            Self::User(_) => IdType::User,
+        }
+    }
+
+    /// The canonical Spotify URI of the resource, e.g. `spotify:track:6rqhFgbbKwnb9MLmUQDhG6`.
+    pub fn uri(&self) -> String {
+        format!("spotify:{}:{}", self.id_type(), self.id())
+    }
+
+    /// The `open.spotify.com` link to the resource, e.g. `https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6`.
+    pub fn url(&self) -> String {
+        format!("https://open.spotify.com/{}/{}", self.id_type(), self.id())
+    }
+}
+
+impl std::fmt::Display for SpotifyUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.url())
+        } else {
+            write!(f, "{}", self.uri())
+        }
+    }
+}
+
+impl std::str::FromStr for SpotifyUri {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for SpotifyUri {
+    type Error = IdError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::parse(input)
+    }
+}
+
+macro_rules! impl_spotify_uri_conversions {
+    ($(($variant:ident, $id:ident)),* $(,)?) => {
+        $(
+            impl From<$id> for SpotifyUri {
+                fn from(id: $id) -> Self {
+                    Self::$variant(id)
+                }
+            }
+
+            impl TryFrom<SpotifyUri> for $id {
+                type Error = IdError;
+
+                fn try_from(uri: SpotifyUri) -> Result<Self, Self::Error> {
+                    match uri {
+                        SpotifyUri::$variant(id) => Ok(id),
+                        uri => Err(IdError::UnexpectedType {
+                            expected: IdType::$variant,
+                            got: uri.id_type(),
+                        }),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_spotify_uri_conversions![
+    (Album, AlbumId),
+    (Artist, ArtistId),
+    (Audiobook, AudiobookId),
+    (Chapter, ChapterId),
+    (Episode, EpisodeId),
+    (Playlist, PlaylistId),
+    (Show, ShowId),
+    (Track, TrackId),
+    (User, UserId),
+];
+
+impl TryFrom<SpotifyUri> for ContextType {
+    type Error = IdError;
+
+    fn try_from(uri: SpotifyUri) -> Result<Self, Self::Error> {
+        match uri {
+            SpotifyUri::Album(id) => Ok(Self::Album(id)),
+            SpotifyUri::Artist(id) => Ok(Self::Artist(id)),
+            SpotifyUri::Playlist(id) => Ok(Self::Playlist(id)),
+            SpotifyUri::Show(id) => Ok(Self::Show(id)),
+            uri => Err(IdError::UnsupportedType {
+                accepted: Self::ID_TYPES,
+                got: uri.id_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<SpotifyUri> for PlaylistItem {
+    type Error = IdError;
+
+    fn try_from(uri: SpotifyUri) -> Result<Self, Self::Error> {
+        match uri {
+            SpotifyUri::Track(id) => Ok(Self::Track(id)),
+            SpotifyUri::Episode(id) => Ok(Self::Episode(id)),
+            uri => Err(IdError::UnsupportedType {
+                accepted: Self::ID_TYPES,
+                got: uri.id_type(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_uris() {
+        let uri = SpotifyUri::parse("spotify:track:6rqhFgbbKwnb9MLmUQDhG6").unwrap();
+        assert_eq!(
+            uri,
+            SpotifyUri::Track(TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap())
+        );
+
+        let uri =
+            SpotifyUri::parse("spotify:user:spotify:playlist:37i9dQZF1DXcBWIGoYBM5M").unwrap();
+        assert_eq!(uri.to_string(), "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M");
+
+        let uri = SpotifyUri::parse("spotify:user:smedjan").unwrap();
+        assert_eq!(uri, SpotifyUri::User(UserId::from_id("smedjan").unwrap()));
+
+        let uri = SpotifyUri::parse("spotify:audiobook:7iHfbu1YPACw6oZPAFJtqe").unwrap();
+        assert_eq!(uri.id_type(), IdType::Audiobook);
+
+        let uri = SpotifyUri::parse("spotify:chapter:0D5wENdkdwbqlrHoaJ9g29").unwrap();
+        assert_eq!(uri.id_type(), IdType::Chapter);
+    }
+
+    #[test]
+    fn parse_urls() {
+        let expected = SpotifyUri::Track(TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap());
+
+        for url in [
+            "https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/intl-de/track/6rqhFgbbKwnb9MLmUQDhG6?si=1a2b3c4d5e6f",
+            "http://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6/",
+            "open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/embed/track/6rqhFgbbKwnb9MLmUQDhG6?utm_source=generator",
+            "https://embed.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            " https://play.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6 ",
+        ] {
+            assert_eq!(SpotifyUri::parse(url).unwrap(), expected, "{url}");
+        }
+
+        let uri = SpotifyUri::parse(
+            "https://open.spotify.com/user/spotify/playlist/37i9dQZF1DXcBWIGoYBM5M?si=abc",
+        )
+        .unwrap();
+        assert_eq!(
+            uri,
+            SpotifyUri::Playlist(PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M").unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in [
+            "",
+            "spotify:",
+            "spotify:user:",
+            "spotify:track:",
+            "spotify:user::playlist:37i9dQZF1DXcBWIGoYBM5M",
+            "spotify:collection:tracks",
+            "spotify:user:smedjan:collection",
+            "https://example.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/",
+        ] {
+            assert_eq!(
+                SpotifyUri::parse(input),
+                Err(IdError::InvalidUri),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            SpotifyUri::parse("https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG"),
+            Err(IdError::InvalidLength {
+                got: 21,
+                expected: 22
+            })
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let uri = SpotifyUri::parse("spotify:episode:512ojhOuo1ktJprKbVcKyQ").unwrap();
+
+        assert_eq!(uri.to_string(), "spotify:episode:512ojhOuo1ktJprKbVcKyQ");
+        assert_eq!(
+            format!("{uri:#}"),
+            "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ"
+        );
+        assert_eq!(uri.to_string().parse::<SpotifyUri>().unwrap(), uri);
+        assert_eq!(format!("{uri:#}").parse::<SpotifyUri>().unwrap(), uri);
+    }
+
+    #[test]
+    fn typed_id_conversions() {
+        let uri = SpotifyUri::parse("spotify:album:5ht7ItJgpBH7W6vJ5BqpPr").unwrap();
+
+        assert_eq!(
+            AlbumId::try_from(uri.clone()).unwrap(),
+            AlbumId::from_id("5ht7ItJgpBH7W6vJ5BqpPr").unwrap()
+        );
+        assert_eq!(
+            TrackId::try_from(uri.clone()),
+            Err(IdError::UnexpectedType {
+                expected: IdType::Track,
+                got: IdType::Album
+            })
+        );
+        assert!(matches!(
+            ContextType::try_from(uri.clone()),
+            Ok(ContextType::Album(_))
+        ));
+
+        let err = PlaylistItem::try_from(uri).unwrap_err();
+        assert_eq!(
+            err,
+            IdError::UnsupportedType {
+                accepted: &[IdType::Track, IdType::Episode],
+                got: IdType::Album
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Expected an ID of type track, episode, got a album ID."
+        );
+    }
+}
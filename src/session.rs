@@ -0,0 +1,1163 @@
+//! Sessions for applications that act on behalf of many Spotify users.
+//!
+//! [`Spotify`](crate::Spotify) binds a single token to a single client, which is a good fit for
+//! desktop and command-line applications. A web backend, on the other hand, serves many users at
+//! once. The [`SessionManager`] (or [`AsyncSessionManager`]) shares one HTTP client and one
+//! Authorization Code PKCE configuration between all users, keeps their tokens in a
+//! [`TokenStore`] keyed by Spotify user id, and hands out lightweight per-user handles that
+//! implement [`api::Client`] (or [`api::AsyncClient`]).
+//!
+//! Each user's token is refreshed independently when it expires, or when Spotify rejects it, and
+//! the refreshed token is written back to the store. Token events are reported with the id of the
+//! user they concern.
+//!
+//! # Example
+//! ```no_run
+//! use spotify_web_api::{
+//!     api::{Query as _, users::GetCurrentUserProfile},
+//!     auth::scopes,
+//!     model::CurrentUserProfile,
+//!     session::SessionManager,
+//! };
+//!
+//! let manager = SessionManager::new(
+//!     "client_id",
+//!     "https://example.com/callback",
+//!     scopes::user_details(),
+//! )
+//! .expect("Failed to create session manager");
+//!
+//! // When the user logs in, redirect them to the URL and keep the pending authorization,
+//! // for example in their cookie session.
+//! let (url, pending) = manager.authorization_url();
+//!
+//! // When Spotify redirects back, complete the authorization.
+//! let redirect_url = "https://example.com/callback?code=...&state=...";
+//! let session = manager
+//!     .complete_authorization(pending, redirect_url)
+//!     .expect("Failed to authorize");
+//!
+//! // Later requests only need the user id.
+//! let session = manager.session(session.user_id()).expect("Unknown user");
+//! let user: CurrentUserProfile = GetCurrentUserProfile.query(&session).unwrap();
+//! ```
+
+use crate::{
+    RestError, SpotifyResult, TokenEvent,
+    api::{self, ApiError, AsyncQuery as _, Query as _, RestClient, users::GetCurrentUserProfile},
+    auth::{
+        AuthCodePKCE, AuthError,
+        pkce::{AuthorizationUrlOptions, PendingAuthorization},
+        private::{AsyncAuthFlow as _, AuthFlow as _},
+        scopes::{RequiredScopes, Scope},
+    },
+    model::Token,
+    spotify::{
+        AsyncTokenRenewal, BASE_ACCOUNTS_URL, BASE_API_URL, HttpClient, Renewal, RenewedToken,
+        TokenRenewal, check_granted_scopes, execute, execute_async, set_token_expiry,
+    },
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::Response as HttpResponse;
+use parking_lot::{Mutex, RwLock};
+use reqwest::{Client as AsyncClient, blocking::Client};
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use url::Url;
+
+/// Persistent storage for the tokens of many users, keyed by Spotify user id.
+///
+/// Implement this trait to keep tokens in a database or a cache shared between processes.
+/// The methods are called on every request, so implementations should be fast.
+pub trait TokenStore: Send + Sync {
+    /// Returns the token stored for a user, if any.
+    fn get(&self, user_id: &str) -> Option<Token>;
+
+    /// Stores the token of a user, replacing any previous token.
+    fn set(&self, user_id: &str, token: Token);
+
+    /// Removes the token of a user, returning it if one was stored.
+    fn remove(&self, user_id: &str) -> Option<Token>;
+}
+
+/// A [`TokenStore`] keeping the tokens in memory.
+///
+/// Tokens are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct InMemoryTokenStore {
+    tokens: RwLock<HashMap<String, Token>>,
+}
+
+impl InMemoryTokenStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for InMemoryTokenStore {
+    fn get(&self, user_id: &str) -> Option<Token> {
+        self.tokens.read().get(user_id).cloned()
+    }
+
+    fn set(&self, user_id: &str, token: Token) {
+        self.tokens.write().insert(user_id.to_owned(), token);
+    }
+
+    fn remove(&self, user_id: &str) -> Option<Token> {
+        self.tokens.write().remove(user_id)
+    }
+}
+
+/// The part of the current user's profile needed to key their token.
+#[derive(Deserialize)]
+struct UserId {
+    id: String,
+}
+
+/// A handler called for every [`TokenEvent`], with the Spotify user id of the user it concerns.
+type SessionEventHandler = dyn Fn(&str, &TokenEvent) + Send + Sync + 'static;
+
+/// The settings of a manager, passed on to the sessions it hands out.
+#[derive(Clone)]
+struct Settings {
+    /// The base URL of the Spotify Accounts service, used for token requests.
+    accounts_url: Url,
+
+    /// Whether to check the granted scopes against the ones required by an endpoint before sending a request.
+    scope_check: bool,
+
+    /// The handlers to call for every token event.
+    token_event_handlers: Vec<Arc<SessionEventHandler>>,
+}
+
+impl Settings {
+    fn new() -> SpotifyResult<Self> {
+        Ok(Self {
+            accounts_url: Url::parse(BASE_ACCOUNTS_URL)?,
+            scope_check: false,
+            token_event_handlers: Vec::new(),
+        })
+    }
+
+    /// Calls the handlers with an event concerning a user.
+    fn emit(&self, user_id: &str, event: &TokenEvent) {
+        for handler in &self.token_event_handlers {
+            handler(user_id, event);
+        }
+    }
+}
+
+/// State shared between a manager and all the sessions it hands out.
+struct Shared<C, L, S> {
+    /// The client to use for API calls.
+    client: C,
+
+    /// The base URL to use for API calls.
+    api_url: Url,
+
+    /// The authentication configuration used for every user.
+    auth: AuthCodePKCE,
+
+    /// The tokens of all users.
+    store: S,
+
+    /// One lock per user, so that concurrent requests refresh an expired token only once.
+    refresh_locks: Mutex<HashMap<String, Arc<L>>>,
+}
+
+impl<C, L, S> Shared<C, L, S>
+where
+    L: Default,
+    S: TokenStore,
+{
+    fn new(client: C, auth: AuthCodePKCE, store: S) -> SpotifyResult<Self> {
+        Ok(Self {
+            client,
+            api_url: Url::parse(BASE_API_URL)?,
+            auth,
+            store,
+            refresh_locks: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn authorization_url(
+        &self,
+        options: &AuthorizationUrlOptions,
+    ) -> (String, PendingAuthorization) {
+        let mut auth = self.auth.clone();
+        let url = auth.user_authorization_url_with(options);
+        let pending = auth
+            .pending_authorization()
+            .expect("a pending authorization exists after generating the authorization url");
+        (url, pending)
+    }
+
+    /// Returns a client that sends requests on behalf of the manager, authorized with `token`.
+    fn http_client<'a>(
+        &'a self,
+        settings: &'a Settings,
+        token: Option<&'a Token>,
+    ) -> HttpClient<'a, C> {
+        HttpClient {
+            client: &self.client,
+            api_url: &self.api_url,
+            accounts_url: &settings.accounts_url,
+            token,
+        }
+    }
+
+    fn refresh_lock(&self, user_id: &str) -> Arc<L> {
+        self.refresh_locks
+            .lock()
+            .entry(user_id.to_owned())
+            .or_default()
+            .clone()
+    }
+
+    /// Stores the token a user was just issued, emitting [`TokenEvent::TokenIssued`].
+    fn store_issued_token(&self, settings: &Settings, user_id: &str, token: Token) {
+        self.store.set(user_id, token.clone());
+        settings.emit(user_id, &TokenEvent::TokenIssued(token));
+    }
+
+    /// Removes the token of a user, emitting [`TokenEvent::TokenCleared`] if one was stored.
+    fn remove(&self, settings: &Settings, user_id: &str) -> Option<Token> {
+        self.refresh_locks.lock().remove(user_id);
+        let token = self.store.remove(user_id)?;
+        settings.emit(user_id, &TokenEvent::TokenCleared);
+        Some(token)
+    }
+
+    /// Stores a renewed token of a user and emits the matching event.
+    ///
+    /// The token is discarded if the user was logged out while it was renewed.
+    fn store_renewed_token(
+        &self,
+        settings: &Settings,
+        user_id: &str,
+        token: Token,
+        renewal: Renewal,
+    ) -> Option<Token> {
+        let previous = self.store.get(user_id)?;
+
+        let mut token = match renewal {
+            Renewal::Refreshed => token.merge_refreshed(&previous),
+            Renewal::Issued => token,
+        };
+        set_token_expiry(&mut token);
+        self.store.set(user_id, token.clone());
+
+        let event = match renewal {
+            Renewal::Refreshed => TokenEvent::TokenRefreshed(token.clone()),
+            Renewal::Issued => TokenEvent::TokenIssued(token.clone()),
+        };
+        settings.emit(user_id, &event);
+
+        Some(token)
+    }
+
+    /// Checks that the token of a user was granted the required scopes, if enabled.
+    fn check_scopes(
+        &self,
+        settings: &Settings,
+        user_id: &str,
+        required: &RequiredScopes,
+    ) -> Result<(), ApiError<RestError>> {
+        if !settings.scope_check {
+            return Ok(());
+        }
+
+        check_granted_scopes(self.store.get(user_id).as_ref(), required)?;
+
+        Ok(())
+    }
+}
+
+/// Manages the tokens of many users for a blocking backend.
+///
+/// The manager is cheap to clone; clones share the same HTTP client and token store.
+pub struct SessionManager<S = InMemoryTokenStore> {
+    shared: Arc<Shared<Client, Mutex<()>, S>>,
+    settings: Arc<Settings>,
+}
+
+impl<S> Clone for SessionManager<S> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+        }
+    }
+}
+
+impl SessionManager<InMemoryTokenStore> {
+    /// Creates a session manager keeping the tokens in memory.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `redirect_uri`: The URI to which users will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    pub fn new(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        Self::with_token_store(client_id, redirect_uri, scopes, InMemoryTokenStore::new())
+    }
+}
+
+impl<S> SessionManager<S>
+where
+    S: TokenStore,
+{
+    /// Creates a session manager keeping the tokens in the given store.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `redirect_uri`: The URI to which users will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    /// - `store`: The [`TokenStore`] holding the tokens of all users.
+    pub fn with_token_store(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+        store: S,
+    ) -> SpotifyResult<Self> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()?;
+        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
+        let shared = Shared::new(client, auth, store)?;
+        Ok(Self {
+            shared: Arc::new(shared),
+            settings: Arc::new(Settings::new()?),
+        })
+    }
+
+    /// Sets the base URL of the Spotify Accounts service, to which token requests are sent.
+    ///
+    /// Defaults to `https://accounts.spotify.com/`. Use this to route token requests through a
+    /// proxy or a mock server.
+    #[must_use]
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        Arc::make_mut(&mut self.settings).accounts_url = url;
+        self
+    }
+
+    /// Enables or disables checking scopes before sending a request.
+    ///
+    /// When enabled, the scopes required by an endpoint (see [`api::Endpoint::required_scopes`])
+    /// are compared with the scopes granted to the user's token, and the request fails with
+    /// [`AuthError::MissingScopes`] without being sent if any of them is missing.
+    ///
+    /// Scope checking is disabled by default.
+    #[must_use]
+    pub fn with_scope_check(mut self, enabled: bool) -> Self {
+        Arc::make_mut(&mut self.settings).scope_check = enabled;
+        self
+    }
+
+    /// Adds a handler to be called for every [`TokenEvent`], with the Spotify user id of the
+    /// user it concerns.
+    ///
+    /// Handlers are called in the order they were added, on the task that caused the event,
+    /// for example when a token is refreshed before a request is sent.
+    #[must_use]
+    pub fn token_event_callback(
+        mut self,
+        handler: impl Fn(&str, &TokenEvent) + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.settings)
+            .token_event_handlers
+            .push(Arc::new(handler));
+        self
+    }
+
+    /// Starts the authorization of a new user.
+    ///
+    /// # Returns
+    /// The URL to redirect the user to, and the pending authorization to keep until Spotify
+    /// redirects back (for example in the user's cookie session).
+    pub fn authorization_url(&self) -> (String, PendingAuthorization) {
+        self.shared
+            .authorization_url(&AuthorizationUrlOptions::default())
+    }
+
+    /// Starts the authorization of a new user with custom options.
+    ///
+    /// Use this to force the consent dialog when users switch accounts, or to carry your own
+    /// payload in the `state` parameter.
+    pub fn authorization_url_with(
+        &self,
+        options: &AuthorizationUrlOptions,
+    ) -> (String, PendingAuthorization) {
+        self.shared.authorization_url(options)
+    }
+
+    /// Completes the authorization of a user and stores their token.
+    ///
+    /// The token is keyed by the id of the Spotify user who granted access, which is looked up
+    /// with the new token.
+    ///
+    /// # Arguments
+    /// * `pending` - The pending authorization returned by [`Self::authorization_url`].
+    /// * `url` - The callback URL redirected to by Spotify after user authorization.
+    ///
+    /// # Returns
+    /// * `Ok(UserSession)` - A session for the user who granted access.
+    /// * `Err(ApiError<RestError>)` - If the pending authorization has expired, the redirect
+    ///   URL is invalid, or a request fails.
+    pub fn complete_authorization(
+        &self,
+        pending: PendingAuthorization,
+        url: &str,
+    ) -> Result<UserSession<S>, ApiError<RestError>> {
+        let mut auth = self.shared.auth.clone();
+        auth.restore_pending_authorization(pending)?;
+
+        let mut token = auth
+            .request_token_from_redirect_url(url, &self.shared.http_client(&self.settings, None))?;
+        set_token_expiry(&mut token);
+
+        let client = self.shared.http_client(&self.settings, Some(&token));
+        let UserId { id } = GetCurrentUserProfile.query(&client)?;
+
+        self.shared.store_issued_token(&self.settings, &id, token);
+        Ok(self.session_unchecked(id))
+    }
+
+    /// Stores a token obtained elsewhere for a user, replacing any previous token.
+    pub fn insert_token(&self, user_id: impl Into<String>, token: Token) -> UserSession<S> {
+        let user_id = user_id.into();
+        self.shared.store.set(&user_id, token);
+        self.session_unchecked(user_id)
+    }
+
+    /// Returns a session for a user, or `None` if no token is stored for them.
+    pub fn session(&self, user_id: impl Into<String>) -> Option<UserSession<S>> {
+        let user_id = user_id.into();
+        self.shared.store.get(&user_id)?;
+        Some(self.session_unchecked(user_id))
+    }
+
+    /// Removes the token of a user, returning it if one was stored.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] if a token was stored.
+    pub fn remove(&self, user_id: &str) -> Option<Token> {
+        self.shared.remove(&self.settings, user_id)
+    }
+
+    /// Returns the store holding the tokens of all users.
+    pub fn token_store(&self) -> &S {
+        &self.shared.store
+    }
+
+    fn session_unchecked(&self, user_id: String) -> UserSession<S> {
+        UserSession {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+            user_id,
+        }
+    }
+}
+
+/// A handle for making requests on behalf of a single user of a [`SessionManager`].
+///
+/// The handle does not own the token; it is read from the manager's [`TokenStore`] on every
+/// request, and refreshed tokens are written back to it.
+pub struct UserSession<S = InMemoryTokenStore> {
+    shared: Arc<Shared<Client, Mutex<()>, S>>,
+    settings: Arc<Settings>,
+    user_id: String,
+}
+
+impl<S> Clone for UserSession<S> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+            user_id: self.user_id.clone(),
+        }
+    }
+}
+
+impl<S> UserSession<S>
+where
+    S: TokenStore,
+{
+    /// Returns the Spotify user id of the session.
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Returns the stored token of the user, if any.
+    pub fn token(&self) -> Option<Token> {
+        self.shared.store.get(&self.user_id)
+    }
+
+    /// Logs the user out by removing their token from the token store.
+    ///
+    /// Requests made with this session, or any other session of the same user, then fail with
+    /// [`AuthError::EmptyAccessToken`] until the user authorizes again. A token refreshed in the
+    /// meantime is discarded. Emits [`TokenEvent::TokenCleared`] and returns the removed token.
+    pub fn logout(&self) -> Option<Token> {
+        self.shared.remove(&self.settings, &self.user_id)
+    }
+
+    /// Refreshes the user's access token using the stored refresh token.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully refreshed and stored.
+    /// * `Err(AuthError::EmptyAccessToken)` - If no token is stored for the user.
+    /// * `Err(AuthError::EmptyRefreshToken)` - If the token has no refresh token.
+    /// * `Err(ApiError<RestError>)` - If the token refresh request fails.
+    pub fn refresh_token(&self) -> Result<(), ApiError<RestError>> {
+        let token = self.token().ok_or(AuthError::EmptyAccessToken)?;
+        if token.refresh_token.is_none() {
+            return Err(AuthError::EmptyRefreshToken.into());
+        }
+
+        self.renew_token(Some(&token))?;
+        Ok(())
+    }
+}
+
+impl<S> TokenRenewal for UserSession<S>
+where
+    S: TokenStore,
+{
+    fn renewal_lock(&self) -> Arc<Mutex<()>> {
+        self.shared.refresh_lock(&self.user_id)
+    }
+
+    fn stored_token(&self) -> Option<Token> {
+        self.token()
+    }
+
+    fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken> {
+        let refresh_token = current?.refresh_token.as_ref()?;
+        let result = self.shared.auth.refresh_token(
+            &self.shared.http_client(&self.settings, None),
+            refresh_token.expose_secret(),
+        );
+        Some((result, Renewal::Refreshed))
+    }
+
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token> {
+        self.shared
+            .store_renewed_token(&self.settings, &self.user_id, token, renewal)
+    }
+
+    fn emit(&self, event: &TokenEvent) {
+        self.settings.emit(&self.user_id, event);
+    }
+
+    fn execute(&self, request: http::Request<Vec<u8>>) -> Result<HttpResponse<Bytes>, RestError> {
+        execute(&self.shared.client, request)
+    }
+}
+
+impl<S> RestClient for UserSession<S>
+where
+    S: TokenStore,
+{
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        log::info!("REST api call {endpoint}");
+        Ok(self.shared.api_url.join(endpoint)?)
+    }
+
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.settings.accounts_url.join(endpoint)?)
+    }
+
+    fn check_scopes(&self, required: &RequiredScopes) -> Result<(), ApiError<Self::Error>> {
+        self.shared
+            .check_scopes(&self.settings, &self.user_id, required)
+    }
+}
+
+impl<S> api::Client for UserSession<S>
+where
+    S: TokenStore,
+{
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<Self::Error>> {
+        self.rest_auth(request, body)
+    }
+}
+
+/// Manages the tokens of many users for an asynchronous backend.
+///
+/// The manager is cheap to clone; clones share the same HTTP client and token store.
+pub struct AsyncSessionManager<S = InMemoryTokenStore> {
+    shared: Arc<Shared<AsyncClient, futures_util::lock::Mutex<()>, S>>,
+    settings: Arc<Settings>,
+}
+
+impl<S> Clone for AsyncSessionManager<S> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+        }
+    }
+}
+
+impl AsyncSessionManager<InMemoryTokenStore> {
+    /// Creates a session manager keeping the tokens in memory.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `redirect_uri`: The URI to which users will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    pub fn new(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        Self::with_token_store(client_id, redirect_uri, scopes, InMemoryTokenStore::new())
+    }
+}
+
+impl<S> AsyncSessionManager<S>
+where
+    S: TokenStore,
+{
+    /// Creates a session manager keeping the tokens in the given store.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `redirect_uri`: The URI to which users will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    /// - `store`: The [`TokenStore`] holding the tokens of all users.
+    pub fn with_token_store(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+        store: S,
+    ) -> SpotifyResult<Self> {
+        let client = AsyncClient::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()?;
+        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
+        let shared = Shared::new(client, auth, store)?;
+        Ok(Self {
+            shared: Arc::new(shared),
+            settings: Arc::new(Settings::new()?),
+        })
+    }
+
+    /// Sets the base URL of the Spotify Accounts service, to which token requests are sent.
+    ///
+    /// Defaults to `https://accounts.spotify.com/`. Use this to route token requests through a
+    /// proxy or a mock server.
+    #[must_use]
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        Arc::make_mut(&mut self.settings).accounts_url = url;
+        self
+    }
+
+    /// Enables or disables checking scopes before sending a request.
+    ///
+    /// When enabled, the scopes required by an endpoint (see [`api::Endpoint::required_scopes`])
+    /// are compared with the scopes granted to the user's token, and the request fails with
+    /// [`AuthError::MissingScopes`] without being sent if any of them is missing.
+    ///
+    /// Scope checking is disabled by default.
+    #[must_use]
+    pub fn with_scope_check(mut self, enabled: bool) -> Self {
+        Arc::make_mut(&mut self.settings).scope_check = enabled;
+        self
+    }
+
+    /// Adds a handler to be called for every [`TokenEvent`], with the Spotify user id of the
+    /// user it concerns.
+    ///
+    /// Handlers are called in the order they were added, on the task that caused the event,
+    /// for example when a token is refreshed before a request is sent.
+    #[must_use]
+    pub fn token_event_callback(
+        mut self,
+        handler: impl Fn(&str, &TokenEvent) + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.settings)
+            .token_event_handlers
+            .push(Arc::new(handler));
+        self
+    }
+
+    /// Starts the authorization of a new user.
+    ///
+    /// # Returns
+    /// The URL to redirect the user to, and the pending authorization to keep until Spotify
+    /// redirects back (for example in the user's cookie session).
+    pub fn authorization_url(&self) -> (String, PendingAuthorization) {
+        self.shared
+            .authorization_url(&AuthorizationUrlOptions::default())
+    }
+
+    /// Starts the authorization of a new user with custom options.
+    ///
+    /// Use this to force the consent dialog when users switch accounts, or to carry your own
+    /// payload in the `state` parameter.
+    pub fn authorization_url_with(
+        &self,
+        options: &AuthorizationUrlOptions,
+    ) -> (String, PendingAuthorization) {
+        self.shared.authorization_url(options)
+    }
+
+    /// Completes the authorization of a user and stores their token.
+    ///
+    /// The token is keyed by the id of the Spotify user who granted access, which is looked up
+    /// with the new token.
+    ///
+    /// # Arguments
+    /// * `pending` - The pending authorization returned by [`Self::authorization_url`].
+    /// * `url` - The callback URL redirected to by Spotify after user authorization.
+    ///
+    /// # Returns
+    /// * `Ok(AsyncUserSession)` - A session for the user who granted access.
+    /// * `Err(ApiError<RestError>)` - If the pending authorization has expired, the redirect
+    ///   URL is invalid, or a request fails.
+    pub async fn complete_authorization(
+        &self,
+        pending: PendingAuthorization,
+        url: &str,
+    ) -> Result<AsyncUserSession<S>, ApiError<RestError>> {
+        let mut auth = self.shared.auth.clone();
+        auth.restore_pending_authorization(pending)?;
+
+        let mut token = auth
+            .request_token_from_redirect_url_async(
+                url,
+                &self.shared.http_client(&self.settings, None),
+            )
+            .await?;
+        set_token_expiry(&mut token);
+
+        let client = self.shared.http_client(&self.settings, Some(&token));
+        let UserId { id } = GetCurrentUserProfile.query_async(&client).await?;
+
+        self.shared.store_issued_token(&self.settings, &id, token);
+        Ok(self.session_unchecked(id))
+    }
+
+    /// Stores a token obtained elsewhere for a user, replacing any previous token.
+    pub fn insert_token(&self, user_id: impl Into<String>, token: Token) -> AsyncUserSession<S> {
+        let user_id = user_id.into();
+        self.shared.store.set(&user_id, token);
+        self.session_unchecked(user_id)
+    }
+
+    /// Returns a session for a user, or `None` if no token is stored for them.
+    pub fn session(&self, user_id: impl Into<String>) -> Option<AsyncUserSession<S>> {
+        let user_id = user_id.into();
+        self.shared.store.get(&user_id)?;
+        Some(self.session_unchecked(user_id))
+    }
+
+    /// Removes the token of a user, returning it if one was stored.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] if a token was stored.
+    pub fn remove(&self, user_id: &str) -> Option<Token> {
+        self.shared.remove(&self.settings, user_id)
+    }
+
+    /// Returns the store holding the tokens of all users.
+    pub fn token_store(&self) -> &S {
+        &self.shared.store
+    }
+
+    fn session_unchecked(&self, user_id: String) -> AsyncUserSession<S> {
+        AsyncUserSession {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+            user_id,
+        }
+    }
+}
+
+/// A handle for making requests on behalf of a single user of an [`AsyncSessionManager`].
+///
+/// The handle does not own the token; it is read from the manager's [`TokenStore`] on every
+/// request, and refreshed tokens are written back to it.
+pub struct AsyncUserSession<S = InMemoryTokenStore> {
+    shared: Arc<Shared<AsyncClient, futures_util::lock::Mutex<()>, S>>,
+    settings: Arc<Settings>,
+    user_id: String,
+}
+
+impl<S> Clone for AsyncUserSession<S> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            settings: self.settings.clone(),
+            user_id: self.user_id.clone(),
+        }
+    }
+}
+
+impl<S> AsyncUserSession<S>
+where
+    S: TokenStore,
+{
+    /// Returns the Spotify user id of the session.
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Returns the stored token of the user, if any.
+    pub fn token(&self) -> Option<Token> {
+        self.shared.store.get(&self.user_id)
+    }
+
+    /// Logs the user out by removing their token from the token store.
+    ///
+    /// Requests made with this session, or any other session of the same user, then fail with
+    /// [`AuthError::EmptyAccessToken`] until the user authorizes again. A token refreshed in the
+    /// meantime is discarded. Emits [`TokenEvent::TokenCleared`] and returns the removed token.
+    pub fn logout(&self) -> Option<Token> {
+        self.shared.remove(&self.settings, &self.user_id)
+    }
+
+    /// Refreshes the user's access token using the stored refresh token.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully refreshed and stored.
+    /// * `Err(AuthError::EmptyAccessToken)` - If no token is stored for the user.
+    /// * `Err(AuthError::EmptyRefreshToken)` - If the token has no refresh token.
+    /// * `Err(ApiError<RestError>)` - If the token refresh request fails.
+    pub async fn refresh_token(&self) -> Result<(), ApiError<RestError>> {
+        let token = self.token().ok_or(AuthError::EmptyAccessToken)?;
+        if token.refresh_token.is_none() {
+            return Err(AuthError::EmptyRefreshToken.into());
+        }
+
+        self.renew_token(Some(&token)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S> AsyncTokenRenewal for AsyncUserSession<S>
+where
+    S: TokenStore,
+{
+    fn renewal_lock(&self) -> Arc<futures_util::lock::Mutex<()>> {
+        self.shared.refresh_lock(&self.user_id)
+    }
+
+    fn stored_token(&self) -> Option<Token> {
+        self.token()
+    }
+
+    async fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken> {
+        let refresh_token = current?.refresh_token.as_ref()?;
+        let result = self
+            .shared
+            .auth
+            .refresh_token_async(
+                &self.shared.http_client(&self.settings, None),
+                refresh_token.expose_secret(),
+            )
+            .await;
+        Some((result, Renewal::Refreshed))
+    }
+
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token> {
+        self.shared
+            .store_renewed_token(&self.settings, &self.user_id, token, renewal)
+    }
+
+    fn emit(&self, event: &TokenEvent) {
+        self.settings.emit(&self.user_id, event);
+    }
+
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<HttpResponse<Bytes>, RestError> {
+        execute_async(&self.shared.client, request).await
+    }
+}
+
+impl<S> RestClient for AsyncUserSession<S>
+where
+    S: TokenStore,
+{
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        log::info!("REST api call {endpoint}");
+        Ok(self.shared.api_url.join(endpoint)?)
+    }
+
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.settings.accounts_url.join(endpoint)?)
+    }
+
+    fn check_scopes(&self, required: &RequiredScopes) -> Result<(), ApiError<Self::Error>> {
+        self.shared
+            .check_scopes(&self.settings, &self.user_id, required)
+    }
+}
+
+#[async_trait]
+impl<S> api::AsyncClient for AsyncUserSession<S>
+where
+    S: TokenStore,
+{
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<Self::Error>> {
+        self.rest_async_auth(request, body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::server::{TOKEN, serve};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn token(access_token: &str) -> Token {
+        Token {
+            access_token: access_token.into(),
+            token_type: "Bearer".to_owned(),
+            expires_in: 3600,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn in_memory_token_store() {
+        let store = InMemoryTokenStore::new();
+        assert!(store.get("alice").is_none());
+
+        store.set("alice", token("a"));
+        store.set("bob", token("b"));
+        store.set("alice", token("c"));
+
+        assert_eq!(
+            store.get("alice").unwrap().access_token.expose_secret(),
+            "c"
+        );
+        assert_eq!(
+            store.remove("bob").unwrap().access_token.expose_secret(),
+            "b"
+        );
+        assert!(store.get("bob").is_none());
+    }
+
+    #[test]
+    fn sessions_share_the_store() {
+        let manager =
+            SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None).unwrap();
+
+        assert!(manager.session("alice").is_none());
+
+        let session = manager.insert_token("alice", token("a"));
+        assert_eq!(session.user_id(), "alice");
+
+        let other = manager.clone().session("alice").unwrap();
+        assert_eq!(other.token().unwrap().access_token.expose_secret(), "a");
+        assert_eq!(
+            other.rest_endpoint("me").unwrap().as_str(),
+            "https://api.spotify.com/v1/me"
+        );
+
+        assert!(manager.remove("alice").is_some());
+        assert!(session.token().is_none());
+    }
+
+    #[test]
+    fn logout_removes_the_token() {
+        let manager =
+            SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None).unwrap();
+        let session = manager.insert_token("alice", token("a"));
+
+        assert!(session.logout().is_some());
+        assert!(manager.session("alice").is_none());
+
+        let err = GetCurrentUserProfile
+            .query(&session)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Auth(AuthError::EmptyAccessToken)));
+    }
+
+    #[test]
+    fn authorization_urls_are_independent() {
+        let manager =
+            AsyncSessionManager::new("client_id", "http://127.0.0.1:8888/callback", None).unwrap();
+
+        let (first_url, first) = manager.authorization_url();
+        let (second_url, second) = manager.authorization_url();
+
+        assert_ne!(first_url, second_url);
+        assert_ne!(first.state, second.state);
+    }
+
+    #[test]
+    fn refreshed_token_replaced_concurrently() {
+        let manager =
+            SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None).unwrap();
+        let session = manager.insert_token(
+            "alice",
+            Token {
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..token("new")
+            },
+        );
+
+        let current = session.renew_token(Some(&token("old"))).unwrap().unwrap();
+
+        assert_eq!(current.access_token.expose_secret(), "new");
+    }
+
+    #[test]
+    fn refreshed_token_is_discarded_after_logout() {
+        let manager =
+            SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None).unwrap();
+        let session = manager.insert_token("alice", token("old"));
+
+        assert!(session.logout().is_some());
+
+        let renewed = session.store_renewed_token(token("new"), Renewal::Refreshed);
+
+        assert!(renewed.is_none());
+        assert!(session.token().is_none());
+    }
+
+    #[test]
+    fn expired_token_is_refreshed_and_reported() {
+        let (url, requests) = serve(|request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ => (200, r#"{"id": "alice"}"#),
+        });
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None)
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .token_event_callback({
+                let events = events.clone();
+                move |user_id, event| events.lock().push((user_id.to_owned(), event.clone()))
+            });
+        Arc::get_mut(&mut manager.shared).unwrap().api_url = url.join("v1/").unwrap();
+
+        let session = manager.insert_token(
+            "alice",
+            Token {
+                refresh_token: Some("refresh".into()),
+                expires_at: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+                ..token("expired")
+            },
+        );
+
+        let user: serde_json::Value = GetCurrentUserProfile.query(&session).unwrap();
+
+        assert_eq!(user["id"], "alice");
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["POST /api/token", "GET /v1/me"]
+        );
+
+        let token = session.token().unwrap();
+        assert_eq!(token.access_token.expose_secret(), "token");
+        assert_eq!(
+            token
+                .refresh_token
+                .as_ref()
+                .map(|token| token.expose_secret().as_str()),
+            Some("refresh")
+        );
+
+        assert!(matches!(
+            events.lock().as_slice(),
+            [
+                (expired, TokenEvent::TokenExpired),
+                (refreshed, TokenEvent::TokenRefreshed(_)),
+            ] if expired == "alice" && refreshed == "alice"
+        ));
+    }
+
+    #[tokio::test]
+    async fn async_unauthorized_request_is_retried_once_with_refreshed_token() {
+        const EXPIRED: &str =
+            r#"{"error": {"status": 401, "message": "The access token expired"}}"#;
+
+        let rejected = AtomicBool::new(false);
+        let (url, requests) = serve(move |request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ if !rejected.swap(true, Ordering::SeqCst) => (401, EXPIRED),
+            _ => (200, r#"{"id": "alice"}"#),
+        });
+
+        let mut manager =
+            AsyncSessionManager::new("client_id", "http://127.0.0.1:8888/callback", None)
+                .unwrap()
+                .with_accounts_url(url.clone());
+        Arc::get_mut(&mut manager.shared).unwrap().api_url = url.join("v1/").unwrap();
+
+        let session = manager.insert_token(
+            "alice",
+            Token {
+                refresh_token: Some("refresh".into()),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..token("stale")
+            },
+        );
+
+        let user: serde_json::Value = GetCurrentUserProfile.query_async(&session).await.unwrap();
+
+        assert_eq!(user["id"], "alice");
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+        assert_eq!(
+            session.token().unwrap().access_token.expose_secret(),
+            "token"
+        );
+    }
+
+    #[test]
+    fn scope_check_rejects_missing_scopes() {
+        use crate::api::users::GetFollowedArtists;
+
+        let manager = SessionManager::new("client_id", "http://127.0.0.1:8888/callback", None)
+            .unwrap()
+            .with_scope_check(true);
+        let session = manager.insert_token(
+            "alice",
+            Token {
+                scope: Some("user-read-private".to_owned()),
+                ..token("a")
+            },
+        );
+
+        let err = GetFollowedArtists::default()
+            .query(&session)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Auth(AuthError::MissingScopes { .. })
+        ));
+    }
+}
@@ -1,22 +1,39 @@
+#[cfg(feature = "callback_server")]
+use crate::auth::callback_server::CallbackServer;
 use crate::{
     api::{self, ApiError, RestClient},
     auth::{
         AuthCodePKCE, AuthError, AuthResult, ClientCredentials,
+        pkce::{AuthorizationUrlOptions, PendingAuthorization},
         private::{AsyncAuthFlow, AuthFlow},
-        scopes::Scope,
+        scopes::{self, RequiredScopes, Scope},
     },
     model::Token,
 };
 use async_trait::async_trait;
 use bytes::Bytes;
 use http::{HeaderMap, HeaderValue, Response as HttpResponse};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use reqwest::{Client as AsyncClient, blocking::Client};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 use thiserror::Error;
 use url::Url;
 
-const BASE_API_URL: &str = "https://api.spotify.com/v1/";
+mod events;
+mod renewal;
+
+use events::TokenEvents;
+pub use events::{RefreshError, TokenEvent, TokenEventStream};
+pub(crate) use renewal::{AsyncTokenRenewal, Renewal, RenewedToken, TokenRenewal};
+
+pub(crate) const BASE_API_URL: &str = "https://api.spotify.com/v1/";
+pub(crate) const BASE_ACCOUNTS_URL: &str = "https://accounts.spotify.com/";
 
 pub type SpotifyPKCE = Spotify<AuthCodePKCE>;
 pub type SpotifyClientCredentials = Spotify<ClientCredentials>;
@@ -24,6 +41,189 @@ pub type AsyncSpotifyPKCE = AsyncSpotify<AuthCodePKCE>;
 pub type AsyncSpotifyClientCredentials = AsyncSpotify<ClientCredentials>;
 pub type SpotifyResult<T> = Result<T, SpotifyError>;
 
+/// Checks that a token was granted the required scopes.
+///
+/// Nothing is checked if no token is stored yet, so that the missing token is reported instead.
+pub(crate) fn check_granted_scopes(
+    token: Option<&Token>,
+    required: &RequiredScopes,
+) -> AuthResult<()> {
+    let Some(token) = token else {
+        return Ok(());
+    };
+
+    let granted = token
+        .scope
+        .as_deref()
+        .map(scopes::parse)
+        .unwrap_or_default();
+
+    if required.is_satisfied_by(&granted) {
+        Ok(())
+    } else {
+        Err(AuthError::MissingScopes {
+            required: required.to_set(),
+            granted,
+        })
+    }
+}
+
+/// Copies a request so that it can be sent more than once.
+pub(crate) fn clone_request(request: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+/// Adds the bearer authorization header for a token to a set of headers.
+///
+/// Returns an error if the token string cannot be parsed as a header value.
+pub(crate) fn set_bearer_header(
+    headers: &mut HeaderMap<HeaderValue>,
+    token: &Token,
+) -> AuthResult<()> {
+    let value = format!("Bearer {}", token.access_token.expose_secret());
+    let mut token_header_value = HeaderValue::from_str(&value).map_err(AuthError::from)?;
+    token_header_value.set_sensitive(true);
+    headers.insert(http::header::AUTHORIZATION, token_header_value);
+    Ok(())
+}
+
+/// The message Spotify sends in the body of a `401 Unauthorized` response for an expired token.
+const TOKEN_EXPIRED_MESSAGE: &str = "The access token expired";
+
+/// Checks whether a `401 Unauthorized` response was caused by the token having expired, rather
+/// than by it being revoked or otherwise invalid.
+fn is_token_expired_response(rsp: &HttpResponse<Bytes>, token: &Token) -> bool {
+    token.is_expired()
+        || serde_json::from_slice::<serde_json::Value>(rsp.body()).is_ok_and(|body| {
+            body.pointer("/error/message")
+                .and_then(serde_json::Value::as_str)
+                == Some(TOKEN_EXPIRED_MESSAGE)
+        })
+}
+
+/// Sets the expiration time of a freshly issued token from its `expires_in` field.
+pub(crate) fn set_token_expiry(token: &mut Token) {
+    token.expires_at =
+        chrono::Utc::now().checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
+}
+
+/// Sends a request with the blocking client and converts the response.
+pub(crate) fn execute(
+    client: &Client,
+    request: http::Request<Vec<u8>>,
+) -> Result<HttpResponse<Bytes>, RestError> {
+    let request = request.try_into()?;
+    let rsp = client.execute(request)?;
+
+    let mut http_rsp = HttpResponse::builder()
+        .status(rsp.status())
+        .version(rsp.version());
+
+    let headers = http_rsp
+        .headers_mut()
+        .expect("failed to get headers on the request builder");
+
+    for (key, value) in rsp.headers() {
+        headers.insert(key, value.clone());
+    }
+
+    Ok(http_rsp.body(rsp.bytes()?)?)
+}
+
+/// Sends a request with the asynchronous client and converts the response.
+pub(crate) async fn execute_async(
+    client: &AsyncClient,
+    request: http::Request<Vec<u8>>,
+) -> Result<HttpResponse<Bytes>, RestError> {
+    let request = request.try_into()?;
+    let rsp = client.execute(request).await?;
+
+    let mut http_rsp = HttpResponse::builder()
+        .status(rsp.status())
+        .version(rsp.version());
+
+    let headers = http_rsp
+        .headers_mut()
+        .expect("failed to get headers on the request builder");
+
+    for (key, value) in rsp.headers() {
+        headers.insert(key, value.clone());
+    }
+
+    Ok(http_rsp.body(rsp.bytes().await?)?)
+}
+
+/// A client that sends requests through an HTTP client without renewing any token.
+///
+/// It is used for requests the authorization flows make on their own, such as token requests to
+/// the Accounts service. Requests are authorized with `token` when one is given.
+pub(crate) struct HttpClient<'a, C> {
+    pub(crate) client: &'a C,
+    pub(crate) api_url: &'a Url,
+    pub(crate) accounts_url: &'a Url,
+    pub(crate) token: Option<&'a Token>,
+}
+
+impl<C> HttpClient<'_, C> {
+    fn build_request(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<http::Request<Vec<u8>>, ApiError<RestError>> {
+        let mut request = request.body(body).map_err(|e| ApiError::client(e.into()))?;
+
+        if let Some(token) = self.token {
+            set_bearer_header(request.headers_mut(), token)?;
+        }
+
+        Ok(request)
+    }
+}
+
+impl<C> RestClient for HttpClient<'_, C> {
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        log::debug!("REST api endpoint {endpoint}");
+        Ok(self.api_url.join(endpoint)?)
+    }
+
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        log::debug!("REST accounts endpoint {endpoint}");
+        Ok(self.accounts_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for HttpClient<'_, Client> {
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<Self::Error>> {
+        let request = self.build_request(request, body)?;
+        execute(self.client, request).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for HttpClient<'_, AsyncClient> {
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<Self::Error>> {
+        let request = self.build_request(request, body)?;
+        execute_async(self.client, request)
+            .await
+            .map_err(ApiError::client)
+    }
+}
+
 /// Represents errors that can occur during communication with the Spotify API.
 ///
 /// This enum defines various error conditions that may arise while interacting
@@ -136,6 +336,11 @@ impl SpotifyError {
     }
 }
 
+/// A blocking client for the Spotify Web API.
+///
+/// The client is cheap to clone: clones share the same HTTP client, authentication flow,
+/// access token, and event handlers. It is `Send` and `Sync` when its authentication flow is,
+/// so a single client can be shared across a thread pool.
 pub struct Spotify<A>
 where
     A: AuthFlow,
@@ -146,14 +351,53 @@ where
     /// The base URL to use for API calls.
     api_url: Url,
 
+    /// The base URL of the Spotify Accounts service, used for token requests.
+    accounts_url: Url,
+
     /// The authentication flow to use for API calls.
-    auth: A,
+    auth: Arc<RwLock<A>>,
 
     /// The current access token, if available.
     token: Arc<RwLock<Option<Token>>>,
 
+    /// A lock shared by all clones, so that concurrent requests renew an expired token only once.
+    refresh_lock: Arc<Mutex<()>>,
+
     /// A handler to call when the access token acquires a new value.
-    token_callback: Option<Box<dyn Fn(Token) + 'static>>,
+    token_callback: Option<Arc<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// A handler to call when the stored access token is removed.
+    token_cleared_callback: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+
+    /// The handlers and streams to notify of token lifecycle events.
+    token_events: Arc<TokenEvents>,
+
+    /// Whether to check the granted scopes against the ones required by an endpoint before sending a request.
+    scope_check: bool,
+
+    /// Whether the client was logged out, so that no token is requested on its behalf.
+    logged_out: Arc<AtomicBool>,
+}
+
+impl<A> Clone for Spotify<A>
+where
+    A: AuthFlow,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            api_url: self.api_url.clone(),
+            accounts_url: self.accounts_url.clone(),
+            auth: self.auth.clone(),
+            token: self.token.clone(),
+            refresh_lock: self.refresh_lock.clone(),
+            token_callback: self.token_callback.clone(),
+            token_cleared_callback: self.token_cleared_callback.clone(),
+            token_events: self.token_events.clone(),
+            scope_check: self.scope_check,
+            logged_out: self.logged_out.clone(),
+        }
+    }
 }
 
 impl<A> Spotify<A>
@@ -162,92 +406,34 @@ where
 {
     fn new_impl(auth: A) -> SpotifyResult<Self> {
         let api_url = Url::parse(BASE_API_URL)?;
+        let accounts_url = Url::parse(BASE_ACCOUNTS_URL)?;
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(10))
             .build()?;
         let api = Self {
             client,
             api_url,
-            auth,
+            accounts_url,
+            auth: Arc::new(RwLock::new(auth)),
             token: Arc::new(RwLock::new(None)),
+            refresh_lock: Arc::new(Mutex::new(())),
             token_callback: None,
+            token_cleared_callback: None,
+            token_events: Arc::new(TokenEvents::default()),
+            scope_check: false,
+            logged_out: Arc::new(AtomicBool::new(false)),
         };
         Ok(api)
     }
 
-    /// Perform a REST query with a given auth.
-    fn rest_auth(
-        &self,
-        mut request: http::request::Builder,
-        body: Vec<u8>,
-    ) -> Result<HttpResponse<Bytes>, ApiError<<Self as RestClient>::Error>> {
-        let is_expired = self
-            .token
-            .read()
-            .as_ref()
-            .ok_or(AuthError::EmptyAccessToken)?
-            .is_expired();
-
-        let refresh_token = if is_expired {
-            self.token
-                .read()
-                .as_ref()
-                .ok_or(AuthError::EmptyAccessToken)?
-                .refresh_token
-                .clone()
-        } else {
-            None
-        };
-
-        if let Some(refresh_token) = refresh_token {
-            let new_token = self.auth.refresh_token(&self.client, &refresh_token)?;
-            self.set_token(new_token);
+    /// Returns a client that sends the requests of the authentication flow.
+    fn http_client(&self) -> HttpClient<'_, Client> {
+        HttpClient {
+            client: &self.client,
+            api_url: &self.api_url,
+            accounts_url: &self.accounts_url,
+            token: None,
         }
-
-        let call = || -> Result<_, RestError> {
-            self.set_header(
-                request
-                    .headers_mut()
-                    .expect("failed to get headers on the request builder"),
-            )?;
-
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request)?;
-
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-
-            let headers = http_rsp
-                .headers_mut()
-                .expect("failed to get headers on the request builder");
-
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
-            }
-
-            Ok(http_rsp.body(rsp.bytes()?)?)
-        };
-
-        call().map_err(ApiError::client)
-    }
-
-    /// Adds the appropriate header to a set of headers.
-    ///
-    /// Returns an error if the token string cannot be parsed as a header value or if the token is empty.
-    fn set_header<'a>(
-        &self,
-        headers: &'a mut HeaderMap<HeaderValue>,
-    ) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
-        let token = self.token.read();
-        let token = token.as_ref().ok_or(AuthError::EmptyAccessToken)?;
-
-        let value = format!("Bearer {}", token.access_token);
-        let mut token_header_value = HeaderValue::from_str(&value).map_err(AuthError::from)?;
-        token_header_value.set_sensitive(true);
-        headers.insert(http::header::AUTHORIZATION, token_header_value);
-        Ok(headers)
     }
 
     /// Returns a shared reference to the stored access token.
@@ -286,15 +472,143 @@ where
         Ok(Some(s))
     }
 
-    fn set_token(&self, mut token: Token) {
-        token.expires_at = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
+    /// Returns the scopes granted to the stored access token.
+    ///
+    /// # Returns
+    /// The set of scopes listed in the token's `scope` field, or an empty set if no token is
+    /// stored or the token does not list its scopes.
+    pub fn granted_scopes(&self) -> HashSet<Scope> {
+        self.token
+            .read()
+            .as_ref()
+            .and_then(|token| token.scope.as_deref())
+            .map(scopes::parse)
+            .unwrap_or_default()
+    }
+
+    /// Sets the base URL of the Spotify Accounts service, to which token requests are sent.
+    ///
+    /// Defaults to `https://accounts.spotify.com/`. Use this to route token requests through a
+    /// proxy or a mock server.
+    #[must_use]
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        self.accounts_url = url;
+        self
+    }
+
+    /// Enables or disables checking scopes before sending a request.
+    ///
+    /// When enabled, the scopes required by an endpoint (see [`api::Endpoint::required_scopes`])
+    /// are compared with the scopes granted to the stored token, and the request fails with
+    /// [`AuthError::MissingScopes`] without being sent if any of them is missing.
+    ///
+    /// Scope checking is disabled by default.
+    #[must_use]
+    pub fn with_scope_check(mut self, enabled: bool) -> Self {
+        self.scope_check = enabled;
+        self
+    }
+
+    /// Adds a handler to be called for every [`TokenEvent`].
+    ///
+    /// Handlers are called in the order they were added, on the task that caused the event,
+    /// for example when a token is refreshed before a request is sent.
+    #[must_use]
+    pub fn token_event_callback(
+        self,
+        handler: impl Fn(&TokenEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.token_events.add_handler(Arc::new(handler));
+        self
+    }
+
+    /// Returns a stream of the [`TokenEvent`]s emitted from now on.
+    ///
+    /// Up to 64 events are buffered until they are read, and newer events are dropped for this
+    /// stream while its buffer is full, so the stream must be polled regularly. Handlers added
+    /// with [`Self::token_event_callback`] receive every event. The stream ends when the client
+    /// and all its clones are dropped.
+    pub fn token_events(&self) -> TokenEventStream {
+        self.token_events.subscribe()
+    }
+
+    /// Removes the stored access token.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] if a token was stored.
+    pub fn clear_token(&self) {
+        if self.token.write().take().is_some() {
+            self.token_cleared();
+        }
+    }
+
+    /// Logs out, discarding the stored access token and any authorization in progress.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] and calls the handler set with
+    /// `token_cleared_callback()` if a token was stored, so that a persisted copy can be removed.
+    /// Requests then fail with [`AuthError::EmptyAccessToken`] until a new token is requested or
+    /// stored, even with flows that can obtain a token on their own, such as Client Credentials.
+    ///
+    /// A token renewal in progress is waited for, and its token is discarded.
+    pub fn logout(&self) {
+        let cleared = {
+            let _guard = self.refresh_lock.lock();
+            self.auth.write().reset();
+            self.logged_out.store(true, Ordering::Release);
+            self.token.write().take().is_some()
+        };
+
+        if cleared {
+            self.token_cleared();
+        }
+    }
+
+    /// Notifies the handlers that the stored token was removed.
+    fn token_cleared(&self) {
+        if let Some(callback) = &self.token_cleared_callback {
+            callback();
+        }
+
+        self.token_events.emit(&TokenEvent::TokenCleared);
+    }
+
+    /// Stores a token requested by the user of the client, which ends a logout.
+    fn set_requested_token(&self, token: Token) -> Token {
+        self.logged_out.store(false, Ordering::Release);
+        self.set_token(token)
+    }
+
+    fn set_token(&self, token: Token) -> Token {
+        let token = self.store_token(token);
+        self.token_events
+            .emit(&TokenEvent::TokenIssued(token.clone()));
+        token
+    }
+
+    /// Stores a token obtained from a refresh request.
+    ///
+    /// The refresh token and scopes of the previous token are kept when the response omits them.
+    fn set_refreshed_token(&self, token: Token) -> Token {
+        let token = match self.token.read().as_ref() {
+            Some(previous) => token.merge_refreshed(previous),
+            None => token,
+        };
+
+        let token = self.store_token(token);
+        self.token_events
+            .emit(&TokenEvent::TokenRefreshed(token.clone()));
+        token
+    }
+
+    /// Stores a new token, notifying the token callback, and returns a copy of it.
+    fn store_token(&self, mut token: Token) -> Token {
+        set_token_expiry(&mut token);
 
         if let Some(callback) = &self.token_callback {
             callback(token.clone());
         }
 
-        *self.token.write() = Some(token);
+        *self.token.write() = Some(token.clone());
+        token
     }
 }
 
@@ -345,24 +659,37 @@ impl Spotify<AuthCodePKCE> {
     ///
     /// # Returns
     /// The updated `Spotify` instance with the new token set.
-    pub fn with_token(mut self, token: Token) -> Self {
-        let mut scopes = HashSet::new();
-
-        if let Some(scope_str) = &token.scope {
-            for s in scope_str.split_whitespace() {
-                if let Ok(scope) = Scope::try_from(s) {
-                    scopes.insert(scope);
-                }
-            }
-        }
-        self.auth.set_scopes(Some(scopes));
-        self.token = Arc::new(RwLock::new(Some(token)));
+    pub fn with_token(self, token: Token) -> Self {
+        let scopes = token
+            .scope
+            .as_deref()
+            .map(scopes::parse)
+            .unwrap_or_default();
+        self.auth.write().set_scopes(Some(scopes));
+        *self.token.write() = Some(token);
+        self.logged_out.store(false, Ordering::Release);
         self
     }
 
     /// Sets a handler to be called when the access token acquires a new value.
-    pub fn token_callback(mut self, handler: impl Fn(Token) + 'static) -> Self {
-        self.token_callback = Some(Box::new(handler));
+    ///
+    /// After a refresh, the handler receives the merged token, including the refresh token
+    /// carried over from the previous token when Spotify did not rotate it.
+    ///
+    /// The handler is not called when the token is removed, for example by `logout()`; use
+    /// `token_cleared_callback()` for that.
+    pub fn token_callback(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
+        self.token_callback = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets a handler to be called when the stored access token is removed, by `logout()` or
+    /// `clear_token()`.
+    ///
+    /// Together with `token_callback()`, this keeps a persisted copy of the token in sync, for
+    /// example by deleting it on logout.
+    pub fn token_cleared_callback(mut self, handler: impl Fn() + Send + Sync + 'static) -> Self {
+        self.token_cleared_callback = Some(Arc::new(handler));
         self
     }
 
@@ -375,7 +702,50 @@ impl Spotify<AuthCodePKCE> {
     /// # Returns
     /// * `String` - The fully constructed authorization URL.
     pub fn user_authorization_url(&mut self) -> String {
-        self.auth.user_authorization_url()
+        self.auth.write().user_authorization_url()
+    }
+
+    /// Constructs the full URL for user authorization with custom options.
+    ///
+    /// This method behaves like `user_authorization_url()`, but can force the consent dialog,
+    /// use a custom `state` parameter, or change the length of the code verifier.
+    /// A custom state is still verified by `verify_authorization_code()`.
+    ///
+    /// # Arguments
+    /// * `options` - The [`AuthorizationUrlOptions`] to apply.
+    ///
+    /// # Returns
+    /// * `String` - The fully constructed authorization URL.
+    pub fn user_authorization_url_with(&mut self, options: &AuthorizationUrlOptions) -> String {
+        self.auth.write().user_authorization_url_with(options)
+    }
+
+    /// Constructs an authorization URL that upgrades the current session with additional scopes.
+    ///
+    /// Spotify does not add scopes to an existing grant, so the URL requests the union of the
+    /// scopes already granted to the stored token, the scopes configured on this client, and
+    /// `additional_scopes`.
+    ///
+    /// Complete the upgrade with `request_token_from_redirect_url()`. The current token stays
+    /// stored and usable until the new token is received, and is then replaced in a single step,
+    /// along with the scopes configured on this client. If the user denies the request, or never
+    /// completes it, the current token and the configured scopes are kept.
+    ///
+    /// # Arguments
+    /// * `additional_scopes` - The scopes to request on top of the ones already granted.
+    ///
+    /// # Returns
+    /// * `String` - The fully constructed authorization URL.
+    pub fn incremental_authorization_url(
+        &mut self,
+        additional_scopes: impl Into<HashSet<Scope>>,
+    ) -> String {
+        let mut scopes = self.granted_scopes();
+        let mut auth = self.auth.write();
+        scopes.extend(auth.scopes().into_iter().flatten().copied());
+        scopes.extend(additional_scopes.into());
+
+        auth.authorization_url_with_scopes(&AuthorizationUrlOptions::default(), Some(scopes))
     }
 
     /// Verifies the authorization code and state returned in the callback URL.
@@ -396,8 +766,46 @@ impl Spotify<AuthCodePKCE> {
     /// * `AuthError::CodeNotFound` - Returned if the `code` parameter is missing in the URL.
     /// * `AuthError::InvalidState` - Returned if the `state` parameter is missing or does not match
     ///   the expected value.
+    /// * `AuthError::AuthorizationDenied` - Returned if the user denied the authorization request.
+    /// * Other OAuth variants, such as `AuthError::InvalidScope` - Returned if Spotify redirected
+    ///   with another `error` parameter.
     pub fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
-        self.auth.verify_authorization_code(url)
+        self.auth.read().verify_authorization_code(url)
+    }
+
+    /// Exports the state of the authorization request started by `user_authorization_url()`.
+    ///
+    /// The returned [`PendingAuthorization`] can be serialized and stored, so that the redirect
+    /// can be handled by another request, process, or client instance.
+    ///
+    /// # Returns
+    /// * `Some(PendingAuthorization)` - If an authorization URL was generated.
+    /// * `None` - If no authorization request is pending.
+    pub fn pending_authorization(&self) -> Option<PendingAuthorization> {
+        self.auth.read().pending_authorization()
+    }
+
+    /// Restores the state of an authorization request exported with `pending_authorization()`.
+    ///
+    /// The redirect URI of the pending authorization replaces the one configured on this client.
+    /// Its scopes replace the configured ones once the token is requested.
+    ///
+    /// # Errors
+    /// * `AuthError::PendingAuthorizationExpired` - Returned if the pending authorization has expired.
+    pub fn restore_pending_authorization(
+        &mut self,
+        pending: PendingAuthorization,
+    ) -> AuthResult<()> {
+        self.auth.write().restore_pending_authorization(pending)
+    }
+
+    /// Stores a token obtained with an authorization code, adopting the scopes requested in the
+    /// authorization URL as the scopes configured on this client.
+    fn set_authorized_token(&self, auth: &AuthCodePKCE, token: Token) {
+        self.auth
+            .write()
+            .set_scopes(auth.requested_scopes().cloned());
+        self.set_requested_token(token);
     }
 
     /// Requests an access token using the provided authorization code.
@@ -413,8 +821,9 @@ impl Spotify<AuthCodePKCE> {
     /// * `Ok(())` - If the token was successfully retrieved and stored.
     /// * `Err(ApiError<RestError>)` - If the token request fails due to network issues, invalid authorization code, or other API errors.
     pub fn request_token(&self, code: &str) -> Result<(), ApiError<RestError>> {
-        let token = self.auth.request_token(code, &self.client)?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth.request_token(code, &self.http_client())?;
+        self.set_authorized_token(&auth, token);
         Ok(())
     }
 
@@ -431,18 +840,65 @@ impl Spotify<AuthCodePKCE> {
     /// * `Ok(())` - If the token was successfully retrieved and stored.
     /// * `Err(ApiError<RestError>)` - If the token request fails due to network issues, invalid authorization code, or other API errors.
     pub fn request_token_from_redirect_url(&self, url: &str) -> Result<(), ApiError<RestError>> {
-        let token = self
-            .auth
-            .request_token_from_redirect_url(url, &self.client)?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth.request_token_from_redirect_url(url, &self.http_client())?;
+        self.set_authorized_token(&auth, token);
         Ok(())
     }
 
+    /// Requests an access token using a restored pending authorization and the redirect URL.
+    ///
+    /// This method combines `restore_pending_authorization` and `request_token_from_redirect_url`,
+    /// allowing a fresh client to complete an authorization request started elsewhere.
+    ///
+    /// # Arguments
+    /// * `pending` - The pending authorization exported when the authorization URL was generated.
+    /// * `url` - A string slice containing the callback URL redirected to by Spotify after user authorization.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully retrieved and stored.
+    /// * `Err(ApiError<RestError>)` - If the pending authorization has expired, or if the token request fails.
+    pub fn request_token_from_pending_authorization(
+        &mut self,
+        pending: PendingAuthorization,
+        url: &str,
+    ) -> Result<(), ApiError<RestError>> {
+        self.restore_pending_authorization(pending)?;
+        self.request_token_from_redirect_url(url)
+    }
+
+    /// Runs the authorization flow using a local callback server.
+    ///
+    /// This method generates a user authorization URL, opens it in the browser (or passes it to
+    /// the server's [`on_authorization_url`](CallbackServer::on_authorization_url) handler),
+    /// waits for Spotify to redirect to the redirect URI, and exchanges the authorization code
+    /// for an access token. The redirect URI must point to a loopback address.
+    ///
+    /// # Arguments
+    /// * `server` - The [`CallbackServer`] configuration to use.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully retrieved and stored.
+    /// * `Err(ApiError<RestError>)` - If the callback server fails or times out, or if the token request fails.
+    #[cfg(feature = "callback_server")]
+    pub fn authorize_with_callback_server(
+        &mut self,
+        server: &CallbackServer,
+    ) -> Result<(), ApiError<RestError>> {
+        let authorization_url = self.user_authorization_url();
+        let redirect_uri = self.auth.read().redirect_uri().to_owned();
+        let redirect_url = server.wait_for_redirect(&redirect_uri, &authorization_url)?;
+        self.request_token_from_redirect_url(redirect_url.as_str())
+    }
+
     /// Refreshes the access token using the stored refresh token.
     ///
     /// This method retrieves a new access token by exchanging the stored refresh token.
     /// It requires that a valid refresh token is present in the current token.
     ///
+    /// If Spotify does not rotate the refresh token, or omits the granted scopes, the values
+    /// from the previous token are kept.
+    ///
     /// # Returns
     /// * `Ok(())` - If the token was successfully refreshed and updated.
     /// * `Err(AuthError::EmptyAccessToken)` - If no token is available.
@@ -450,6 +906,8 @@ impl Spotify<AuthCodePKCE> {
     /// * `Err(ApiError<RestError>)` - If the token refresh request fails due to network issues
     ///   or other API errors.
     pub fn refresh_token(&self) -> Result<(), ApiError<RestError>> {
+        let _guard = self.refresh_lock.lock();
+
         let refresh_token = self
             .token
             .read()
@@ -459,8 +917,11 @@ impl Spotify<AuthCodePKCE> {
             .clone()
             .ok_or(AuthError::EmptyRefreshToken)?;
 
-        let token = self.auth.refresh_token(&self.client, &refresh_token)?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth
+            .refresh_token(&self.http_client(), refresh_token.expose_secret())
+            .inspect_err(|e| self.token_events.emit(&TokenEvent::refresh_failed(e)))?;
+        self.set_refreshed_token(token);
 
         Ok(())
     }
@@ -513,11 +974,12 @@ impl Spotify<ClientCredentials> {
     /// The updated `Spotify` instance with the new token set.
     ///
     /// # Note:
-    /// Once the token is expired, subsequent requests will fail.
-    pub fn with_token(mut self, mut token: Token) -> Self {
+    /// Once the token is expired, a new one is requested automatically before the next request.
+    pub fn with_token(self, mut token: Token) -> Self {
         token.refresh_token = None;
         token.scope = None;
-        self.token = Arc::new(RwLock::new(Some(token)));
+        *self.token.write() = Some(token);
+        self.logged_out.store(false, Ordering::Release);
         self
     }
 
@@ -527,6 +989,9 @@ impl Spotify<ClientCredentials> {
     /// The access token is required to authenticate API requests. The obtained token is stored
     /// internally and is valid for the duration specified by Spotify.
     ///
+    /// Calling this method is optional: if no token is stored, or the stored token has expired,
+    /// a new one is requested automatically before the next API request.
+    ///
     /// # Returns
     /// - `Ok(())`: If the token was successfully retrieved and stored.
     /// - `Err(ApiError<RestError>)`: If the token request fails due to network issues, invalid credentials, or other API errors.
@@ -544,8 +1009,9 @@ impl Spotify<ClientCredentials> {
     /// spotify.request_token().expect("Failed to request token");
     /// ```
     pub fn request_token(&self) -> Result<(), ApiError<RestError>> {
-        let token = self.auth.request_token(&self.client)?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth.request_token(&self.http_client())?;
+        self.set_requested_token(token);
         Ok(())
     }
 }
@@ -560,6 +1026,69 @@ where
         log::info!("REST api call {endpoint}");
         Ok(self.api_url.join(endpoint)?)
     }
+
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.accounts_url.join(endpoint)?)
+    }
+
+    fn check_scopes(&self, required: &RequiredScopes) -> Result<(), ApiError<Self::Error>> {
+        if !self.scope_check {
+            return Ok(());
+        }
+
+        check_granted_scopes(self.token.read().as_ref(), required)?;
+
+        Ok(())
+    }
+}
+
+impl<A> TokenRenewal for Spotify<A>
+where
+    A: AuthFlow,
+{
+    fn renewal_lock(&self) -> Arc<Mutex<()>> {
+        self.refresh_lock.clone()
+    }
+
+    fn stored_token(&self) -> Option<Token> {
+        self.token.read().clone()
+    }
+
+    fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken> {
+        let auth = self.auth.read().clone();
+
+        if let Some(refresh_token) = current.and_then(|token| token.refresh_token.as_ref()) {
+            let result = auth.refresh_token(&self.http_client(), refresh_token.expose_secret());
+            Some((result, Renewal::Refreshed))
+        } else if self.logged_out.load(Ordering::Acquire) {
+            None
+        } else {
+            auth.reissue_token(&self.http_client())
+                .map(|result| (result, Renewal::Issued))
+        }
+    }
+
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token> {
+        // No token is stored on behalf of a client that was logged out.
+        if self.logged_out.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let token = match renewal {
+            Renewal::Refreshed => self.set_refreshed_token(token),
+            Renewal::Issued => self.set_token(token),
+        };
+
+        Some(token)
+    }
+
+    fn emit(&self, event: &TokenEvent) {
+        self.token_events.emit(event);
+    }
+
+    fn execute(&self, request: http::Request<Vec<u8>>) -> Result<HttpResponse<Bytes>, RestError> {
+        execute(&self.client, request)
+    }
 }
 
 impl<A> api::Client for Spotify<A>
@@ -585,14 +1114,32 @@ where
     /// The base URL to use for API calls.
     api_url: Url,
 
+    /// The base URL of the Spotify Accounts service, used for token requests.
+    accounts_url: Url,
+
     /// The authentication flow to use for API calls.
-    auth: A,
+    auth: RwLock<A>,
 
     /// The current access token, if available.
     token: Arc<RwLock<Option<Token>>>,
 
+    /// A lock so that concurrent requests renew an expired token only once.
+    refresh_lock: Arc<futures_util::lock::Mutex<()>>,
+
     /// A handler to call when the access token acquires a new value.
     token_callback: Option<Box<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// A handler to call when the stored access token is removed.
+    token_cleared_callback: Option<Box<dyn Fn() + Send + Sync + 'static>>,
+
+    /// The handlers and streams to notify of token lifecycle events.
+    token_events: TokenEvents,
+
+    /// Whether to check the granted scopes against the ones required by an endpoint before sending a request.
+    scope_check: bool,
+
+    /// Whether the client was logged out, so that no token is requested on its behalf.
+    logged_out: AtomicBool,
 }
 
 impl<A> AsyncSpotify<A>
@@ -601,98 +1148,34 @@ where
 {
     fn new_impl(auth: A) -> SpotifyResult<Self> {
         let api_url = Url::parse(BASE_API_URL)?;
+        let accounts_url = Url::parse(BASE_ACCOUNTS_URL)?;
         let client = AsyncClient::builder()
             .timeout(std::time::Duration::from_secs(10))
             .build()?;
         let api = Self {
             client,
             api_url,
-            auth,
+            accounts_url,
+            auth: RwLock::new(auth),
             token: Arc::new(RwLock::new(None)),
+            refresh_lock: Arc::new(futures_util::lock::Mutex::new(())),
             token_callback: None,
+            token_cleared_callback: None,
+            token_events: TokenEvents::default(),
+            scope_check: false,
+            logged_out: AtomicBool::new(false),
         };
         Ok(api)
     }
 
-    /// Perform a REST query with a given auth.
-    async fn rest_async_auth(
-        &self,
-        mut request: http::request::Builder,
-        body: Vec<u8>,
-    ) -> Result<HttpResponse<Bytes>, ApiError<<Self as RestClient>::Error>> {
-        use futures_util::TryFutureExt;
-
-        let is_expired = self
-            .token
-            .read()
-            .as_ref()
-            .ok_or(AuthError::EmptyAccessToken)?
-            .is_expired();
-
-        let refresh_token = if is_expired {
-            self.token
-                .read()
-                .as_ref()
-                .ok_or(AuthError::EmptyAccessToken)?
-                .refresh_token
-                .clone()
-        } else {
-            None
-        };
-
-        if let Some(refresh_token) = refresh_token {
-            let new_token = self
-                .auth
-                .refresh_token_async(&self.client, &refresh_token)
-                .await?;
-
-            self.set_token(new_token);
+    /// Returns a client that sends the requests of the authentication flow.
+    fn http_client(&self) -> HttpClient<'_, AsyncClient> {
+        HttpClient {
+            client: &self.client,
+            api_url: &self.api_url,
+            accounts_url: &self.accounts_url,
+            token: None,
         }
-
-        let call = || async {
-            self.set_header(
-                request
-                    .headers_mut()
-                    .expect("failed to get headers on the request builder"),
-            )?;
-
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request).await?;
-
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-
-            let headers = http_rsp
-                .headers_mut()
-                .expect("failed to get headers on the request builder");
-
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
-            }
-
-            Ok(http_rsp.body(rsp.bytes().await?)?)
-        };
-
-        call().map_err(ApiError::client).await
-    }
-
-    /// Adds the appropriate header to a set of headers.
-    ///
-    /// Returns an error if the token string cannot be parsed as a header value or if the token is empty.
-    fn set_header<'a>(
-        &self,
-        headers: &'a mut HeaderMap<HeaderValue>,
-    ) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
-        let token = self.token.read();
-        let token = token.as_ref().ok_or(AuthError::EmptyAccessToken)?;
-
-        let value = format!("Bearer {}", token.access_token);
-        let mut token_header_value = HeaderValue::from_str(&value).map_err(AuthError::from)?;
-        token_header_value.set_sensitive(true);
-        headers.insert(http::header::AUTHORIZATION, token_header_value);
-        Ok(headers)
     }
 
     /// Returns a shared reference to the stored access token.
@@ -731,15 +1214,143 @@ where
         Ok(Some(s))
     }
 
-    fn set_token(&self, mut token: Token) {
-        token.expires_at = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
+    /// Returns the scopes granted to the stored access token.
+    ///
+    /// # Returns
+    /// The set of scopes listed in the token's `scope` field, or an empty set if no token is
+    /// stored or the token does not list its scopes.
+    pub fn granted_scopes(&self) -> HashSet<Scope> {
+        self.token
+            .read()
+            .as_ref()
+            .and_then(|token| token.scope.as_deref())
+            .map(scopes::parse)
+            .unwrap_or_default()
+    }
+
+    /// Sets the base URL of the Spotify Accounts service, to which token requests are sent.
+    ///
+    /// Defaults to `https://accounts.spotify.com/`. Use this to route token requests through a
+    /// proxy or a mock server.
+    #[must_use]
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        self.accounts_url = url;
+        self
+    }
+
+    /// Enables or disables checking scopes before sending a request.
+    ///
+    /// When enabled, the scopes required by an endpoint (see [`api::Endpoint::required_scopes`])
+    /// are compared with the scopes granted to the stored token, and the request fails with
+    /// [`AuthError::MissingScopes`] without being sent if any of them is missing.
+    ///
+    /// Scope checking is disabled by default.
+    #[must_use]
+    pub fn with_scope_check(mut self, enabled: bool) -> Self {
+        self.scope_check = enabled;
+        self
+    }
+
+    /// Adds a handler to be called for every [`TokenEvent`].
+    ///
+    /// Handlers are called in the order they were added, on the task that caused the event,
+    /// for example when a token is refreshed before a request is sent.
+    #[must_use]
+    pub fn token_event_callback(
+        self,
+        handler: impl Fn(&TokenEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.token_events.add_handler(Arc::new(handler));
+        self
+    }
+
+    /// Returns a stream of the [`TokenEvent`]s emitted from now on.
+    ///
+    /// Up to 64 events are buffered until they are read, and newer events are dropped for this
+    /// stream while its buffer is full, so the stream must be polled regularly. Handlers added
+    /// with [`Self::token_event_callback`] receive every event. The stream ends when the client
+    /// is dropped.
+    pub fn token_events(&self) -> TokenEventStream {
+        self.token_events.subscribe()
+    }
+
+    /// Removes the stored access token.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] if a token was stored.
+    pub fn clear_token(&self) {
+        if self.token.write().take().is_some() {
+            self.token_cleared();
+        }
+    }
+
+    /// Logs out, discarding the stored access token and any authorization in progress.
+    ///
+    /// Emits [`TokenEvent::TokenCleared`] and calls the handler set with
+    /// `token_cleared_callback()` if a token was stored, so that a persisted copy can be removed.
+    /// Requests then fail with [`AuthError::EmptyAccessToken`] until a new token is requested or
+    /// stored, even with flows that can obtain a token on their own, such as Client Credentials.
+    ///
+    /// A token renewal in progress is waited for, and its token is discarded.
+    pub async fn logout(&self) {
+        let cleared = {
+            let _guard = self.refresh_lock.lock().await;
+            self.auth.write().reset();
+            self.logged_out.store(true, Ordering::Release);
+            self.token.write().take().is_some()
+        };
+
+        if cleared {
+            self.token_cleared();
+        }
+    }
+
+    /// Notifies the handlers that the stored token was removed.
+    fn token_cleared(&self) {
+        if let Some(callback) = &self.token_cleared_callback {
+            callback();
+        }
+
+        self.token_events.emit(&TokenEvent::TokenCleared);
+    }
+
+    /// Stores a token requested by the user of the client, which ends a logout.
+    fn set_requested_token(&self, token: Token) -> Token {
+        self.logged_out.store(false, Ordering::Release);
+        self.set_token(token)
+    }
+
+    fn set_token(&self, token: Token) -> Token {
+        let token = self.store_token(token);
+        self.token_events
+            .emit(&TokenEvent::TokenIssued(token.clone()));
+        token
+    }
+
+    /// Stores a token obtained from a refresh request.
+    ///
+    /// The refresh token and scopes of the previous token are kept when the response omits them.
+    fn set_refreshed_token(&self, token: Token) -> Token {
+        let token = match self.token.read().as_ref() {
+            Some(previous) => token.merge_refreshed(previous),
+            None => token,
+        };
+
+        let token = self.store_token(token);
+        self.token_events
+            .emit(&TokenEvent::TokenRefreshed(token.clone()));
+        token
+    }
+
+    /// Stores a new token, notifying the token callback, and returns a copy of it.
+    fn store_token(&self, mut token: Token) -> Token {
+        set_token_expiry(&mut token);
 
         if let Some(callback) = &self.token_callback {
             callback(token.clone());
         }
 
-        *self.token.write() = Some(token);
+        *self.token.write() = Some(token.clone());
+        token
     }
 }
 
@@ -790,26 +1401,40 @@ impl AsyncSpotify<AuthCodePKCE> {
     ///
     /// # Returns
     /// The updated `Spotify` instance with the new token set.
-    pub fn with_token(mut self, token: Token) -> Self {
-        let mut scopes = HashSet::new();
-        if let Some(scope_str) = &token.scope {
-            for s in scope_str.split_whitespace() {
-                if let Ok(scope) = Scope::try_from(s) {
-                    scopes.insert(scope);
-                }
-            }
-        }
-        self.auth.set_scopes(Some(scopes));
-        self.token = Arc::new(RwLock::new(Some(token)));
+    pub fn with_token(self, token: Token) -> Self {
+        let scopes = token
+            .scope
+            .as_deref()
+            .map(scopes::parse)
+            .unwrap_or_default();
+        self.auth.write().set_scopes(Some(scopes));
+        *self.token.write() = Some(token);
+        self.logged_out.store(false, Ordering::Release);
         self
     }
 
     /// Sets a handler to be called when the access token acquires a new value.
+    ///
+    /// After a refresh, the handler receives the merged token, including the refresh token
+    /// carried over from the previous token when Spotify did not rotate it.
+    ///
+    /// The handler is not called when the token is removed, for example by `logout()`; use
+    /// `token_cleared_callback()` for that.
     pub fn token_callback(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
         self.token_callback = Some(Box::new(handler));
         self
     }
 
+    /// Sets a handler to be called when the stored access token is removed, by `logout()` or
+    /// `clear_token()`.
+    ///
+    /// Together with `token_callback()`, this keeps a persisted copy of the token in sync, for
+    /// example by deleting it on logout.
+    pub fn token_cleared_callback(mut self, handler: impl Fn() + Send + Sync + 'static) -> Self {
+        self.token_cleared_callback = Some(Box::new(handler));
+        self
+    }
+
     /// Constructs the full URL for user authorization.
     ///
     /// This method generates the state and code verifier parameters to produce the complete
@@ -819,7 +1444,50 @@ impl AsyncSpotify<AuthCodePKCE> {
     /// # Returns
     /// * `String` - The fully constructed authorization URL.
     pub fn user_authorization_url(&mut self) -> String {
-        self.auth.user_authorization_url()
+        self.auth.write().user_authorization_url()
+    }
+
+    /// Constructs the full URL for user authorization with custom options.
+    ///
+    /// This method behaves like `user_authorization_url()`, but can force the consent dialog,
+    /// use a custom `state` parameter, or change the length of the code verifier.
+    /// A custom state is still verified by `verify_authorization_code()`.
+    ///
+    /// # Arguments
+    /// * `options` - The [`AuthorizationUrlOptions`] to apply.
+    ///
+    /// # Returns
+    /// * `String` - The fully constructed authorization URL.
+    pub fn user_authorization_url_with(&mut self, options: &AuthorizationUrlOptions) -> String {
+        self.auth.write().user_authorization_url_with(options)
+    }
+
+    /// Constructs an authorization URL that upgrades the current session with additional scopes.
+    ///
+    /// Spotify does not add scopes to an existing grant, so the URL requests the union of the
+    /// scopes already granted to the stored token, the scopes configured on this client, and
+    /// `additional_scopes`.
+    ///
+    /// Complete the upgrade with `request_token_from_redirect_url()`. The current token stays
+    /// stored and usable until the new token is received, and is then replaced in a single step,
+    /// along with the scopes configured on this client. If the user denies the request, or never
+    /// completes it, the current token and the configured scopes are kept.
+    ///
+    /// # Arguments
+    /// * `additional_scopes` - The scopes to request on top of the ones already granted.
+    ///
+    /// # Returns
+    /// * `String` - The fully constructed authorization URL.
+    pub fn incremental_authorization_url(
+        &mut self,
+        additional_scopes: impl Into<HashSet<Scope>>,
+    ) -> String {
+        let mut scopes = self.granted_scopes();
+        let mut auth = self.auth.write();
+        scopes.extend(auth.scopes().into_iter().flatten().copied());
+        scopes.extend(additional_scopes.into());
+
+        auth.authorization_url_with_scopes(&AuthorizationUrlOptions::default(), Some(scopes))
     }
 
     /// Verifies the authorization code and state returned in the callback URL.
@@ -840,8 +1508,46 @@ impl AsyncSpotify<AuthCodePKCE> {
     /// * `AuthError::CodeNotFound` - Returned if the `code` parameter is missing in the URL.
     /// * `AuthError::InvalidState` - Returned if the `state` parameter is missing or does not match
     ///   the expected value.
+    /// * `AuthError::AuthorizationDenied` - Returned if the user denied the authorization request.
+    /// * Other OAuth variants, such as `AuthError::InvalidScope` - Returned if Spotify redirected
+    ///   with another `error` parameter.
     pub fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
-        self.auth.verify_authorization_code(url)
+        self.auth.read().verify_authorization_code(url)
+    }
+
+    /// Exports the state of the authorization request started by `user_authorization_url()`.
+    ///
+    /// The returned [`PendingAuthorization`] can be serialized and stored, so that the redirect
+    /// can be handled by another request, process, or client instance.
+    ///
+    /// # Returns
+    /// * `Some(PendingAuthorization)` - If an authorization URL was generated.
+    /// * `None` - If no authorization request is pending.
+    pub fn pending_authorization(&self) -> Option<PendingAuthorization> {
+        self.auth.read().pending_authorization()
+    }
+
+    /// Restores the state of an authorization request exported with `pending_authorization()`.
+    ///
+    /// The redirect URI of the pending authorization replaces the one configured on this client.
+    /// Its scopes replace the configured ones once the token is requested.
+    ///
+    /// # Errors
+    /// * `AuthError::PendingAuthorizationExpired` - Returned if the pending authorization has expired.
+    pub fn restore_pending_authorization(
+        &mut self,
+        pending: PendingAuthorization,
+    ) -> AuthResult<()> {
+        self.auth.write().restore_pending_authorization(pending)
+    }
+
+    /// Stores a token obtained with an authorization code, adopting the scopes requested in the
+    /// authorization URL as the scopes configured on this client.
+    fn set_authorized_token(&self, auth: &AuthCodePKCE, token: Token) {
+        self.auth
+            .write()
+            .set_scopes(auth.requested_scopes().cloned());
+        self.set_requested_token(token);
     }
 
     /// Asynchronously requests an access token using the provided authorization code.
@@ -857,8 +1563,9 @@ impl AsyncSpotify<AuthCodePKCE> {
     /// * `Ok(())` - If the token was successfully retrieved and stored.
     /// * `Err(ApiError<RestError>)` - If the token request fails due to network issues, invalid authorization code, or other API errors.
     pub async fn request_token(&self, code: &str) -> Result<(), ApiError<RestError>> {
-        let token = self.auth.request_token_async(code, &self.client).await?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth.request_token_async(code, &self.http_client()).await?;
+        self.set_authorized_token(&auth, token);
         Ok(())
     }
 
@@ -878,19 +1585,72 @@ impl AsyncSpotify<AuthCodePKCE> {
         &self,
         url: &str,
     ) -> Result<(), ApiError<RestError>> {
-        let token = self
-            .auth
-            .request_token_from_redirect_url_async(url, &self.client)
+        let auth = self.auth.read().clone();
+        let token = auth
+            .request_token_from_redirect_url_async(url, &self.http_client())
             .await?;
-        self.set_token(token);
+        self.set_authorized_token(&auth, token);
         Ok(())
     }
 
+    /// Asynchronously requests an access token using a restored pending authorization and the redirect URL.
+    ///
+    /// This method combines `restore_pending_authorization` and `request_token_from_redirect_url`,
+    /// allowing a fresh client to complete an authorization request started elsewhere.
+    ///
+    /// # Arguments
+    /// * `pending` - The pending authorization exported when the authorization URL was generated.
+    /// * `url` - A string slice containing the callback URL redirected to by Spotify after user authorization.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully retrieved and stored.
+    /// * `Err(ApiError<RestError>)` - If the pending authorization has expired, or if the token request fails.
+    pub async fn request_token_from_pending_authorization(
+        &mut self,
+        pending: PendingAuthorization,
+        url: &str,
+    ) -> Result<(), ApiError<RestError>> {
+        self.restore_pending_authorization(pending)?;
+        self.request_token_from_redirect_url(url).await
+    }
+
+    /// Asynchronously runs the authorization flow using a local callback server.
+    ///
+    /// This method generates a user authorization URL, opens it in the browser (or passes it to
+    /// the server's [`on_authorization_url`](CallbackServer::on_authorization_url) handler),
+    /// waits for Spotify to redirect to the redirect URI, and exchanges the authorization code
+    /// for an access token. The redirect URI must point to a loopback address.
+    ///
+    /// This method must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    /// * `server` - The [`CallbackServer`] configuration to use.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the token was successfully retrieved and stored.
+    /// * `Err(ApiError<RestError>)` - If the callback server fails or times out, or if the token request fails.
+    #[cfg(feature = "callback_server")]
+    pub async fn authorize_with_callback_server(
+        &mut self,
+        server: &CallbackServer,
+    ) -> Result<(), ApiError<RestError>> {
+        let authorization_url = self.user_authorization_url();
+        let redirect_uri = self.auth.read().redirect_uri().to_owned();
+        let redirect_url = server
+            .wait_for_redirect_async(&redirect_uri, &authorization_url)
+            .await?;
+        self.request_token_from_redirect_url(redirect_url.as_str())
+            .await
+    }
+
     /// Asynchronously refreshes the access token using the stored refresh token.
     ///
     /// This method retrieves a new access token by exchanging the stored refresh token.
     /// It requires that a valid refresh token is present in the current token.
     ///
+    /// If Spotify does not rotate the refresh token, or omits the granted scopes, the values
+    /// from the previous token are kept.
+    ///
     /// # Returns
     /// * `Ok(())` - If the token was successfully refreshed and updated.
     /// * `Err(AuthError::EmptyAccessToken)` - If no token is available.
@@ -898,6 +1658,8 @@ impl AsyncSpotify<AuthCodePKCE> {
     /// * `Err(ApiError<RestError>)` - If the token refresh request fails due to network issues
     ///   or other API errors.
     pub async fn refresh_token(&self) -> Result<(), ApiError<RestError>> {
+        let _guard = self.refresh_lock.lock().await;
+
         let refresh_token = self
             .token
             .read()
@@ -907,12 +1669,13 @@ impl AsyncSpotify<AuthCodePKCE> {
             .clone()
             .ok_or(AuthError::EmptyRefreshToken)?;
 
-        let token = self
-            .auth
-            .refresh_token_async(&self.client, &refresh_token)
-            .await?;
+        let auth = self.auth.read().clone();
+        let token = auth
+            .refresh_token_async(&self.http_client(), refresh_token.expose_secret())
+            .await
+            .inspect_err(|e| self.token_events.emit(&TokenEvent::refresh_failed(e)))?;
 
-        self.set_token(token);
+        self.set_refreshed_token(token);
 
         Ok(())
     }
@@ -965,11 +1728,12 @@ impl AsyncSpotify<ClientCredentials> {
     /// The updated `Spotify` instance with the new token set.
     ///
     /// # Note:
-    /// Once the token is expired, subsequent requests will fail.
-    pub fn with_token(mut self, mut token: Token) -> Self {
+    /// Once the token is expired, a new one is requested automatically before the next request.
+    pub fn with_token(self, mut token: Token) -> Self {
         token.refresh_token = None;
         token.scope = None;
-        self.token = Arc::new(RwLock::new(Some(token)));
+        *self.token.write() = Some(token);
+        self.logged_out.store(false, Ordering::Release);
         self
     }
 
@@ -979,12 +1743,16 @@ impl AsyncSpotify<ClientCredentials> {
     /// The access token is required to authenticate API requests. The obtained token is stored
     /// internally and is valid for the duration specified by Spotify.
     ///
+    /// Calling this method is optional: if no token is stored, or the stored token has expired,
+    /// a new one is requested automatically before the next API request.
+    ///
     /// # Returns
     /// - `Ok(())`: If the token was successfully retrieved and stored.
     /// - `Err(ApiError<RestError>)`: If the token request fails due to network issues, invalid credentials, or other API errors.
     pub async fn request_token(&self) -> Result<(), ApiError<RestError>> {
-        let token = self.auth.request_token_async(&self.client).await?;
-        self.set_token(token);
+        let auth = self.auth.read().clone();
+        let token = auth.request_token_async(&self.http_client()).await?;
+        self.set_requested_token(token);
         Ok(())
     }
 }
@@ -1000,6 +1768,76 @@ where
         log::info!("REST api call {endpoint}");
         Ok(self.api_url.join(endpoint)?)
     }
+
+    fn accounts_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.accounts_url.join(endpoint)?)
+    }
+
+    fn check_scopes(&self, required: &RequiredScopes) -> Result<(), ApiError<Self::Error>> {
+        if !self.scope_check {
+            return Ok(());
+        }
+
+        check_granted_scopes(self.token.read().as_ref(), required)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<A> AsyncTokenRenewal for AsyncSpotify<A>
+where
+    A: AsyncAuthFlow + Sync + Send,
+{
+    fn renewal_lock(&self) -> Arc<futures_util::lock::Mutex<()>> {
+        self.refresh_lock.clone()
+    }
+
+    fn stored_token(&self) -> Option<Token> {
+        self.token.read().clone()
+    }
+
+    async fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken> {
+        let auth = self.auth.read().clone();
+
+        if let Some(refresh_token) = current.and_then(|token| token.refresh_token.as_ref()) {
+            let result = auth
+                .refresh_token_async(&self.http_client(), refresh_token.expose_secret())
+                .await;
+            Some((result, Renewal::Refreshed))
+        } else if self.logged_out.load(Ordering::Acquire) {
+            None
+        } else {
+            auth.reissue_token_async(&self.http_client())
+                .await
+                .map(|result| (result, Renewal::Issued))
+        }
+    }
+
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token> {
+        // No token is stored on behalf of a client that was logged out.
+        if self.logged_out.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let token = match renewal {
+            Renewal::Refreshed => self.set_refreshed_token(token),
+            Renewal::Issued => self.set_token(token),
+        };
+
+        Some(token)
+    }
+
+    fn emit(&self, event: &TokenEvent) {
+        self.token_events.emit(event);
+    }
+
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<HttpResponse<Bytes>, RestError> {
+        execute_async(&self.client, request).await
+    }
 }
 
 #[async_trait]
@@ -1015,3 +1853,775 @@ where
         self.rest_async_auth(request, body).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::server::{TOKEN, serve};
+    use std::{
+        sync::{Barrier, atomic::AtomicUsize},
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn concurrent_renewals_request_a_single_token() {
+        let (url, requests) = serve(|_| (200, TOKEN));
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url);
+        let barrier = Arc::new(Barrier::new(2));
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let spotify = spotify.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    spotify.renew_token_if_needed().unwrap()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let token = handle.join().unwrap();
+            assert_eq!(token.access_token.expose_secret(), "token");
+        }
+
+        assert_eq!(requests.lock().as_slice(), ["POST /api/token"]);
+    }
+
+    #[test]
+    fn rejected_token_is_renewed_without_expired_event() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        let rejected = AtomicBool::new(false);
+        let (url, requests) = serve(move |request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ if !rejected.swap(true, Ordering::SeqCst) => (
+                401,
+                r#"{"error": {"status": 401, "message": "Invalid access token"}}"#,
+            ),
+            _ => (200, r#"{"id": "user"}"#),
+        });
+
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "revoked".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let spotify = spotify.token_event_callback({
+            let events = events.clone();
+            move |event| events.lock().push(event.clone())
+        });
+
+        let user: serde_json::Value = GetCurrentUserProfile.query(&spotify).unwrap();
+
+        assert_eq!(user["id"], "user");
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+        assert!(matches!(
+            events.lock().as_slice(),
+            [TokenEvent::TokenIssued(_)]
+        ));
+    }
+
+    #[test]
+    fn token_expired_response() {
+        let valid = Token {
+            expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+            ..Default::default()
+        };
+        let response = |body: &'static str| {
+            HttpResponse::builder()
+                .status(401)
+                .body(Bytes::from_static(body.as_bytes()))
+                .unwrap()
+        };
+
+        let expired =
+            response(r#"{"error": {"status": 401, "message": "The access token expired"}}"#);
+        let revoked = response(r#"{"error": {"status": 401, "message": "Invalid access token"}}"#);
+
+        assert!(is_token_expired_response(&expired, &valid));
+        assert!(!is_token_expired_response(&revoked, &valid));
+        assert!(is_token_expired_response(&revoked, &Token::default()));
+    }
+
+    #[test]
+    fn expired_client_credentials_token_is_reissued() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        let (url, requests) = serve(|request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ => (200, r#"{"id": "user"}"#),
+        });
+
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "expired".into(),
+                expires_at: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let _: serde_json::Value = GetCurrentUserProfile.query(&spotify).unwrap();
+
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["POST /api/token", "GET /v1/me"]
+        );
+        assert_eq!(
+            spotify
+                .token()
+                .read()
+                .as_ref()
+                .unwrap()
+                .access_token
+                .expose_secret(),
+            "token"
+        );
+    }
+
+    #[tokio::test]
+    async fn async_expired_client_credentials_token_is_reissued() {
+        use crate::api::{AsyncQuery as _, users::GetCurrentUserProfile};
+
+        let (url, requests) = serve(|request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ => (200, r#"{"id": "user"}"#),
+        });
+
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "expired".into(),
+                expires_at: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let _: serde_json::Value = GetCurrentUserProfile.query_async(&spotify).await.unwrap();
+
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["POST /api/token", "GET /v1/me"]
+        );
+        assert_eq!(
+            spotify
+                .token()
+                .read()
+                .as_ref()
+                .unwrap()
+                .access_token
+                .expose_secret(),
+            "token"
+        );
+    }
+
+    #[tokio::test]
+    async fn async_concurrent_renewals_request_a_single_token() {
+        let (url, requests) = serve(|_| (200, TOKEN));
+        let spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url);
+
+        let (first, second) = tokio::join!(
+            spotify.renew_token_if_needed(),
+            spotify.renew_token_if_needed()
+        );
+
+        assert_eq!(first.unwrap().access_token.expose_secret(), "token");
+        assert_eq!(second.unwrap().access_token.expose_secret(), "token");
+        assert_eq!(requests.lock().as_slice(), ["POST /api/token"]);
+    }
+
+    #[test]
+    fn expired_token_is_refreshed_with_refresh_token() {
+        let (url, requests) = serve(|_| (200, TOKEN));
+
+        let spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_accounts_url(url)
+        .with_token(Token {
+            access_token: "expired".into(),
+            refresh_token: Some("refresh".into()),
+            expires_at: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+            ..Default::default()
+        });
+
+        let token = spotify.renew_token_if_needed().unwrap();
+
+        assert_eq!(token.access_token.expose_secret(), "token");
+        assert_eq!(
+            token
+                .refresh_token
+                .as_ref()
+                .map(|token| token.expose_secret().as_str()),
+            Some("refresh")
+        );
+        assert_eq!(requests.lock().as_slice(), ["POST /api/token"]);
+    }
+
+    #[test]
+    fn valid_token_is_not_renewed() {
+        let (url, requests) = serve(|_| (200, TOKEN));
+
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url)
+            .with_token(Token {
+                access_token: "valid".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+
+        let token = spotify.renew_token_if_needed().unwrap();
+
+        assert_eq!(token.access_token.expose_secret(), "valid");
+        assert!(requests.lock().is_empty());
+    }
+
+    #[test]
+    fn unauthorized_request_is_retried_once_with_renewed_token() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        const EXPIRED: &str =
+            r#"{"error": {"status": 401, "message": "The access token expired"}}"#;
+
+        let rejected = AtomicBool::new(false);
+        let (url, requests) = serve(move |request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ if !rejected.swap(true, Ordering::SeqCst) => (401, EXPIRED),
+            _ => (200, r#"{"id": "user"}"#),
+        });
+
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "stale".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let spotify = spotify.token_event_callback({
+            let events = events.clone();
+            move |event| events.lock().push(event.clone())
+        });
+
+        let user: serde_json::Value = GetCurrentUserProfile.query(&spotify).unwrap();
+
+        assert_eq!(user["id"], "user");
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+        assert!(matches!(
+            events.lock().as_slice(),
+            [TokenEvent::TokenExpired, TokenEvent::TokenIssued(_)]
+        ));
+    }
+
+    #[test]
+    fn unauthorized_retry_is_not_repeated() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        let (url, requests) = serve(|request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ => (
+                401,
+                r#"{"error": {"status": 401, "message": "Invalid access token"}}"#,
+            ),
+        });
+
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "stale".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let err = GetCurrentUserProfile
+            .query(&spotify)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::SpotifyObjectWithStatus {
+                status: http::StatusCode::UNAUTHORIZED,
+                ..
+            }
+        ));
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+    }
+
+    #[tokio::test]
+    async fn async_unauthorized_request_is_retried_once_with_renewed_token() {
+        use crate::api::{AsyncQuery as _, users::GetCurrentUserProfile};
+
+        const EXPIRED: &str =
+            r#"{"error": {"status": 401, "message": "The access token expired"}}"#;
+
+        let rejected = AtomicBool::new(false);
+        let (url, requests) = serve(move |request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ if !rejected.swap(true, Ordering::SeqCst) => (401, EXPIRED),
+            _ => (200, r#"{"id": "user"}"#),
+        });
+
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "stale".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let spotify = spotify.token_event_callback({
+            let events = events.clone();
+            move |event| events.lock().push(event.clone())
+        });
+
+        let user: serde_json::Value = GetCurrentUserProfile.query_async(&spotify).await.unwrap();
+
+        assert_eq!(user["id"], "user");
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+        assert!(matches!(
+            events.lock().as_slice(),
+            [TokenEvent::TokenExpired, TokenEvent::TokenIssued(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn async_unauthorized_retry_is_not_repeated() {
+        use crate::api::{AsyncQuery as _, users::GetCurrentUserProfile};
+
+        let (url, requests) = serve(|request| match request {
+            "POST /api/token" => (200, TOKEN),
+            _ => (
+                401,
+                r#"{"error": {"status": 401, "message": "Invalid access token"}}"#,
+            ),
+        });
+
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url.clone())
+            .with_token(Token {
+                access_token: "stale".into(),
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            });
+        spotify.api_url = url.join("v1/").unwrap();
+
+        let err = GetCurrentUserProfile
+            .query_async(&spotify)
+            .await
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::SpotifyObjectWithStatus {
+                status: http::StatusCode::UNAUTHORIZED,
+                ..
+            }
+        ));
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["GET /v1/me", "POST /api/token", "GET /v1/me"]
+        );
+    }
+
+    #[test]
+    fn incremental_authorization_url_requests_union() {
+        let mut spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_token(Token {
+            access_token: "access".into(),
+            scope: Some("user-read-email".to_owned()),
+            ..Default::default()
+        });
+
+        let url = spotify.incremental_authorization_url(Scope::UserTopRead);
+        let url = Url::parse(&url).unwrap();
+
+        let requested = url
+            .query_pairs()
+            .find(|(key, _)| key == "scope")
+            .map(|(_, value)| scopes::parse(&value))
+            .unwrap();
+
+        assert_eq!(
+            requested,
+            HashSet::from([Scope::UserReadEmail, Scope::UserTopRead])
+        );
+        assert_eq!(spotify.granted_scopes(), Scope::UserReadEmail.into());
+    }
+
+    #[test]
+    fn denied_incremental_authorization_keeps_scopes() {
+        let mut spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_token(Token {
+            access_token: "access".into(),
+            scope: Some("user-read-email".to_owned()),
+            ..Default::default()
+        });
+
+        spotify.incremental_authorization_url(Scope::UserTopRead);
+        let state = spotify.pending_authorization().unwrap().state;
+
+        let denied = format!("http://127.0.0.1:8888/callback?error=access_denied&state={state}");
+        assert!(matches!(
+            spotify.request_token_from_redirect_url(&denied),
+            Err(ApiError::Auth(AuthError::AuthorizationDenied { .. }))
+        ));
+
+        let url = Url::parse(&spotify.user_authorization_url()).unwrap();
+        let requested = url
+            .query_pairs()
+            .find(|(key, _)| key == "scope")
+            .map(|(_, value)| scopes::parse(&value))
+            .unwrap();
+
+        assert_eq!(requested, Scope::UserReadEmail.into());
+        assert_eq!(
+            spotify
+                .token()
+                .read()
+                .as_ref()
+                .unwrap()
+                .access_token
+                .expose_secret(),
+            "access"
+        );
+    }
+
+    #[test]
+    fn completed_incremental_authorization_adopts_scopes() {
+        let (url, _) = serve(|_| (200, TOKEN));
+
+        let mut spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_accounts_url(url)
+        .with_token(Token {
+            access_token: "access".into(),
+            scope: Some("user-read-email".to_owned()),
+            ..Default::default()
+        });
+
+        spotify.incremental_authorization_url(Scope::UserTopRead);
+        assert_eq!(
+            spotify.auth.read().scopes(),
+            Some(&Scope::UserReadEmail.into())
+        );
+
+        let state = spotify.pending_authorization().unwrap().state;
+        let redirect = format!("http://127.0.0.1:8888/callback?code=abc&state={state}");
+        spotify.request_token_from_redirect_url(&redirect).unwrap();
+
+        assert_eq!(
+            spotify.auth.read().scopes(),
+            Some(&HashSet::from([Scope::UserReadEmail, Scope::UserTopRead]))
+        );
+        assert_eq!(
+            spotify
+                .token()
+                .read()
+                .as_ref()
+                .unwrap()
+                .access_token
+                .expose_secret(),
+            "token"
+        );
+    }
+
+    #[test]
+    fn spotify_is_send_sync_clone() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+
+        assert_send_sync_clone::<SpotifyPKCE>();
+        assert_send_sync_clone::<SpotifyClientCredentials>();
+    }
+
+    #[test]
+    fn token_requests_use_accounts_url() {
+        let accounts_url = Url::parse("http://127.0.0.1:8080/accounts/").unwrap();
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(accounts_url);
+
+        let url = spotify
+            .http_client()
+            .accounts_endpoint("api/token")
+            .unwrap();
+
+        assert_eq!(url.as_str(), "http://127.0.0.1:8080/accounts/api/token");
+    }
+
+    #[test]
+    fn spotify_clones_share_state() {
+        let spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap();
+        let mut clone = spotify.clone();
+
+        clone.user_authorization_url();
+        *clone.token().write() = Some(Token::default());
+
+        assert!(spotify.pending_authorization().is_some());
+        assert!(spotify.token().read().is_some());
+    }
+
+    #[test]
+    fn with_token_updates_clones() {
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret").unwrap();
+        let _clone = spotify.clone().with_token(Token {
+            access_token: "access".into(),
+            ..Default::default()
+        });
+
+        assert!(spotify.token().read().is_some());
+    }
+
+    #[test]
+    fn logout_clears_token_and_pending_authorization() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        let spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_token(Token::default());
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut spotify = spotify.token_event_callback({
+            let events = events.clone();
+            move |event| events.lock().push(event.clone())
+        });
+
+        spotify.user_authorization_url_with(&AuthorizationUrlOptions::new());
+        spotify.logout();
+
+        assert!(spotify.token().read().is_none());
+        assert!(spotify.pending_authorization().is_none());
+        assert!(matches!(
+            events.lock().as_slice(),
+            [TokenEvent::TokenCleared]
+        ));
+
+        let err = GetCurrentUserProfile
+            .query(&spotify)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Auth(AuthError::EmptyAccessToken)));
+    }
+
+    #[tokio::test]
+    async fn async_logout_clears_token_and_pending_authorization() {
+        let cleared = Arc::new(AtomicUsize::new(0));
+        let mut spotify = AsyncSpotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_token(Token::default())
+        .token_cleared_callback({
+            let cleared = cleared.clone();
+            move || {
+                cleared.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        spotify.user_authorization_url();
+
+        let spotify = &spotify;
+        spotify.logout().await;
+        spotify.logout().await;
+
+        assert!(spotify.token().read().is_none());
+        assert!(spotify.pending_authorization().is_none());
+        assert_eq!(cleared.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn logout_notifies_token_cleared_callback() {
+        let persisted = Arc::new(Mutex::new(None));
+        let spotify = Spotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:8888/callback",
+            None,
+        )
+        .unwrap()
+        .with_token(Token::default())
+        .token_callback({
+            let persisted = persisted.clone();
+            move |token| *persisted.lock() = Some(token)
+        })
+        .token_cleared_callback({
+            let persisted = persisted.clone();
+            move || *persisted.lock() = None
+        });
+
+        *persisted.lock() = spotify.token().read().clone();
+        spotify.logout();
+
+        assert!(persisted.lock().is_none());
+    }
+
+    #[test]
+    fn logout_discards_renewal_in_progress() {
+        let (started, renewing) = std::sync::mpsc::channel();
+        let (url, requests) = serve(move |_| {
+            let _ = started.send(());
+            thread::sleep(Duration::from_millis(50));
+            (200, TOKEN)
+        });
+
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url);
+
+        let renewal = thread::spawn({
+            let spotify = spotify.clone();
+            move || spotify.renew_token_if_needed().map(|_| ())
+        });
+
+        renewing.recv().unwrap();
+        spotify.logout();
+        let _ = renewal.join().unwrap();
+
+        assert!(spotify.token().read().is_none());
+        assert!(matches!(
+            spotify.renew_token_if_needed(),
+            Err(ApiError::Auth(AuthError::EmptyAccessToken))
+        ));
+        assert_eq!(requests.lock().as_slice(), ["POST /api/token"]);
+    }
+
+    #[test]
+    fn logout_stops_client_credentials_reissue() {
+        use crate::api::{Query as _, users::GetCurrentUserProfile};
+
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret").unwrap();
+        spotify.logout();
+
+        let err = GetCurrentUserProfile
+            .query(&spotify)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Auth(AuthError::EmptyAccessToken)));
+    }
+
+    #[test]
+    fn check_granted_scopes_without_token() {
+        assert!(check_granted_scopes(None, &Scope::UserTopRead.into()).is_ok());
+    }
+
+    #[test]
+    fn check_granted_scopes_missing() {
+        let token = Token {
+            scope: Some("user-read-email user-read-private".to_owned()),
+            ..Default::default()
+        };
+
+        assert!(check_granted_scopes(Some(&token), &scopes::user_details().into()).is_ok());
+
+        let err = check_granted_scopes(Some(&token), &scopes::user_follow().into()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            AuthError::MissingScopes { required, granted }
+                if required == scopes::user_follow() && granted == scopes::user_details()
+        ));
+    }
+
+    #[test]
+    fn check_granted_scopes_single_playlist_modify_scope() {
+        use crate::{
+            api::{playlists::CreatePlaylist, users::UnfollowPlaylist},
+            model::UserId,
+        };
+
+        let token = Token {
+            scope: Some("playlist-modify-private".to_owned()),
+            ..Default::default()
+        };
+        let unfollow = UnfollowPlaylist::try_from("37i9dQZF1DXcBWIGoYBM5M").unwrap();
+        let mut create = CreatePlaylist {
+            id: UserId::from_id("smedjan").unwrap(),
+            name: "New Playlist".to_owned(),
+            public: None,
+            collaborative: None,
+            description: None,
+        };
+
+        let required = RequiredScopes::of(&unfollow);
+        assert!(check_granted_scopes(Some(&token), &required).is_ok());
+
+        create.public = Some(false);
+        let required = RequiredScopes::of(&create);
+        assert!(check_granted_scopes(Some(&token), &required).is_ok());
+
+        create.public = Some(true);
+        let required = RequiredScopes::of(&create);
+        assert!(check_granted_scopes(Some(&token), &required).is_err());
+    }
+
+    #[test]
+    fn check_granted_scopes_without_scope_field() {
+        let token = Token::default();
+
+        assert!(check_granted_scopes(Some(&token), &RequiredScopes::default()).is_ok());
+        assert!(check_granted_scopes(Some(&token), &Scope::UserTopRead.into()).is_err());
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{RestError, api::ApiError, auth::AuthError, model::Token};
+use futures_channel::mpsc::{self, Receiver, Sender};
+use futures_util::Stream;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    fmt,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// An event in the lifecycle of the access token stored in a client.
+///
+/// Events are delivered to the handlers registered with `token_event_callback()` and to the
+/// streams returned by `token_events()`.
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added
+/// in future versions. When matching against it, include a wildcard arm (`_`)
+/// to account for any future variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenEvent {
+    /// A new access token was obtained, either from an authorization code or, for flows that
+    /// do not need user interaction, by requesting a new one.
+    TokenIssued(Token),
+
+    /// The access token was refreshed using the refresh token.
+    ///
+    /// The token is the merged token, including the refresh token carried over from the
+    /// previous token when Spotify did not rotate it.
+    TokenRefreshed(Token),
+
+    /// Obtaining a new access token failed.
+    RefreshFailed {
+        /// The error that caused the failure.
+        error: RefreshError,
+
+        /// Whether the user must authorize the application again, because the refresh token
+        /// is missing, expired, or was revoked.
+        reauthorization_required: bool,
+    },
+
+    /// The stored access token expired.
+    ///
+    /// This is emitted when the token is past its expiration time before a request is sent, or
+    /// when Spotify rejects it as expired. Other `401 Unauthorized` responses, such as for a
+    /// revoked token, still cause a renewal but do not emit this event.
+    TokenExpired,
+
+    /// The stored access token was removed from the client.
+    TokenCleared,
+}
+
+impl TokenEvent {
+    pub(crate) fn refresh_failed(error: &ApiError<RestError>) -> Self {
+        let error = RefreshError::from(error);
+        let reauthorization_required = match &error {
+            RefreshError::OAuth { error, .. } => error == "invalid_grant",
+            RefreshError::EmptyAccessToken | RefreshError::EmptyRefreshToken => true,
+            RefreshError::Other(_) => false,
+        };
+
+        Self::RefreshFailed {
+            error,
+            reauthorization_required,
+        }
+    }
+}
+
+/// The reason obtaining a new access token failed, carried by [`TokenEvent::RefreshFailed`].
+///
+/// The error returned to the caller is not `Clone`, so the event carries this summary of it.
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added
+/// in future versions. When matching against it, include a wildcard arm (`_`)
+/// to account for any future variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RefreshError {
+    /// The Spotify Accounts service rejected the token request.
+    ///
+    /// # Fields
+    /// - `error`: The OAuth error code, such as `invalid_grant` for a refresh token that expired
+    ///   or was revoked, or `invalid_client` for wrong client credentials.
+    /// - `description`: The human-readable description sent by Spotify, if any.
+    OAuth {
+        error: String,
+        description: Option<String>,
+    },
+
+    /// The token response did not contain an access token.
+    EmptyAccessToken,
+
+    /// There was no refresh token to refresh the access token with.
+    EmptyRefreshToken,
+
+    /// Any other failure, such as a network error. Contains the error message.
+    Other(String),
+}
+
+impl From<&ApiError<RestError>> for RefreshError {
+    fn from(error: &ApiError<RestError>) -> Self {
+        let ApiError::Auth(auth_error) = error else {
+            return Self::Other(error.to_string());
+        };
+
+        if let Some((error, description)) = auth_error.oauth_error() {
+            return Self::OAuth {
+                error: error.to_owned(),
+                description: description.map(ToOwned::to_owned),
+            };
+        }
+
+        match auth_error {
+            AuthError::EmptyAccessToken => Self::EmptyAccessToken,
+            AuthError::EmptyRefreshToken => Self::EmptyRefreshToken,
+            _ => Self::Other(error.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OAuth { error, description } => write!(
+                f,
+                "oauth error {error}: {}",
+                description.as_deref().unwrap_or("<none>")
+            ),
+            Self::EmptyAccessToken => f.write_str("access token is empty"),
+            Self::EmptyRefreshToken => f.write_str("refresh token is empty"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+/// The number of events a [`TokenEventStream`] buffers before newer events are dropped.
+pub(crate) const STREAM_CAPACITY: usize = 64;
+
+/// A stream of [`TokenEvent`]s, returned by `token_events()`.
+///
+/// Up to 64 events are buffered until they are read. Events emitted while the buffer is full
+/// are dropped for this stream, so it must be polled regularly to see every event.
+///
+/// The stream ends when the client it was returned by is dropped, along with all the clones
+/// of that client.
+#[derive(Debug)]
+pub struct TokenEventStream {
+    receiver: Receiver<TokenEvent>,
+}
+
+impl Stream for TokenEventStream {
+    type Item = TokenEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// A handler called for every [`TokenEvent`].
+type TokenEventHandler = dyn Fn(&TokenEvent) + Send + Sync + 'static;
+
+/// Delivers token events to registered handlers and streams.
+#[derive(Default)]
+pub(crate) struct TokenEvents {
+    /// The handlers to call for every event.
+    handlers: RwLock<Vec<Arc<TokenEventHandler>>>,
+
+    /// The senders of the streams returned by `subscribe()`.
+    subscribers: Mutex<Vec<Sender<TokenEvent>>>,
+}
+
+impl TokenEvents {
+    pub(crate) fn add_handler(&self, handler: Arc<TokenEventHandler>) {
+        self.handlers.write().push(handler);
+    }
+
+    pub(crate) fn subscribe(&self) -> TokenEventStream {
+        // Every sender gets a slot of its own on top of the buffer.
+        let (sender, receiver) = mpsc::channel(STREAM_CAPACITY - 1);
+        self.subscribers.lock().push(sender);
+        TokenEventStream { receiver }
+    }
+
+    pub(crate) fn emit(&self, event: &TokenEvent) {
+        // The handlers are called without holding the lock, so that they can add handlers or
+        // cause other events themselves.
+        let handlers = self.handlers.read().clone();
+        for handler in &handlers {
+            handler(event);
+        }
+
+        // Streams that were dropped are forgotten, and streams that are full miss the event.
+        self.subscribers
+            .lock()
+            .retain_mut(|sender| match sender.try_send(event.clone()) {
+                Ok(()) => true,
+                Err(e) if e.is_full() => {
+                    log::debug!("token event stream is full, dropping {:?}", e.into_inner());
+                    true
+                }
+                Err(_) => false,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{FutureExt as _, StreamExt as _};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn events_reach_handlers_and_streams() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        let events = TokenEvents::default();
+        events.add_handler(Arc::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let mut stream = events.subscribe();
+        let dropped = events.subscribe();
+        drop(dropped);
+
+        events.emit(&TokenEvent::TokenExpired);
+        events.emit(&TokenEvent::TokenCleared);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(events.subscribers.lock().len(), 1);
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(TokenEvent::TokenExpired))
+        );
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(TokenEvent::TokenCleared))
+        );
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn full_streams_drop_events() {
+        let events = TokenEvents::default();
+        let mut stream = events.subscribe();
+
+        for _ in 0..STREAM_CAPACITY {
+            events.emit(&TokenEvent::TokenExpired);
+        }
+        events.emit(&TokenEvent::TokenCleared);
+
+        let mut received = 0;
+        while let Some(Some(event)) = stream.next().now_or_never() {
+            assert_eq!(event, TokenEvent::TokenExpired);
+            received += 1;
+        }
+        assert_eq!(received, STREAM_CAPACITY);
+        assert_eq!(events.subscribers.lock().len(), 1);
+
+        events.emit(&TokenEvent::TokenCleared);
+
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(TokenEvent::TokenCleared))
+        );
+    }
+
+    #[test]
+    fn handlers_can_add_handlers() {
+        let events = Arc::new(TokenEvents::default());
+        let weak = Arc::downgrade(&events);
+
+        events.add_handler(Arc::new(move |_| {
+            if let Some(events) = weak.upgrade() {
+                events.add_handler(Arc::new(|_| {}));
+            }
+        }));
+
+        events.emit(&TokenEvent::TokenExpired);
+
+        assert_eq!(events.handlers.read().len(), 2);
+    }
+
+    #[test]
+    fn refresh_failed_requires_reauthorization() {
+        let error = ApiError::<RestError>::Auth(AuthError::InvalidGrant { description: None });
+
+        assert_eq!(
+            TokenEvent::refresh_failed(&error),
+            TokenEvent::RefreshFailed {
+                error: RefreshError::OAuth {
+                    error: "invalid_grant".to_owned(),
+                    description: None,
+                },
+                reauthorization_required: true,
+            }
+        );
+    }
+
+    #[test]
+    fn refresh_failed_keeps_other_errors() {
+        let error = ApiError::<RestError>::Auth(AuthError::InvalidClient {
+            description: Some("Invalid client secret".to_owned()),
+        });
+
+        assert_eq!(
+            TokenEvent::refresh_failed(&error),
+            TokenEvent::RefreshFailed {
+                error: RefreshError::OAuth {
+                    error: "invalid_client".to_owned(),
+                    description: Some("Invalid client secret".to_owned()),
+                },
+                reauthorization_required: false,
+            }
+        );
+
+        let error = ApiError::<RestError>::Auth(AuthError::NoState);
+
+        assert!(matches!(
+            TokenEvent::refresh_failed(&error),
+            TokenEvent::RefreshFailed {
+                error: RefreshError::Other(_),
+                reauthorization_required: false,
+            }
+        ));
+    }
+}
@@ -0,0 +1,255 @@
+use super::{TokenEvent, clone_request, is_token_expired_response, set_bearer_header};
+use crate::{RestError, api::ApiError, auth::AuthError, model::Token};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::Response as HttpResponse;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// How a renewed access token was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Renewal {
+    /// The token was refreshed using the refresh token of the previous one.
+    Refreshed,
+
+    /// A new token was requested without user interaction, such as with Client Credentials.
+    Issued,
+}
+
+/// The result of requesting a renewed access token, along with how it was obtained.
+pub(crate) type RenewedToken = (Result<Token, ApiError<RestError>>, Renewal);
+
+/// A blocking client that renews its access token on its own.
+///
+/// Implementors provide access to the stored token and the way to obtain a new one, and get
+/// the renewal of expired tokens and the single retry of rejected requests in return.
+pub(crate) trait TokenRenewal {
+    /// Returns the lock serializing the renewals of the token.
+    fn renewal_lock(&self) -> Arc<Mutex<()>>;
+
+    /// Returns the stored access token, if any.
+    fn stored_token(&self) -> Option<Token>;
+
+    /// Requests a token to replace `current`, or returns `None` if there is no way of obtaining
+    /// one without user interaction.
+    fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken>;
+
+    /// Stores a renewed token and returns a copy of it, or returns `None` if the token must be
+    /// discarded, for example because the client was logged out in the meantime.
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token>;
+
+    /// Notifies the handlers of a token event.
+    fn emit(&self, event: &TokenEvent);
+
+    /// Sends a request without authorization.
+    fn execute(&self, request: http::Request<Vec<u8>>) -> Result<HttpResponse<Bytes>, RestError>;
+
+    /// Sends a request authorized with the given access token.
+    fn send(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        token: &Token,
+    ) -> Result<HttpResponse<Bytes>, RestError> {
+        let mut http_request = clone_request(request);
+        set_bearer_header(http_request.headers_mut(), token)?;
+        self.execute(http_request)
+    }
+
+    /// Performs a REST query with the stored access token, renewing it first if it expired.
+    ///
+    /// If Spotify rejects the access token with a `401 Unauthorized` response, the token is
+    /// renewed once and the request is replayed.
+    fn rest_auth(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<RestError>> {
+        let token = self.renew_token_if_needed()?;
+
+        let request = request.body(body).map_err(|e| ApiError::client(e.into()))?;
+
+        let rsp = self.send(&request, &token).map_err(ApiError::client)?;
+
+        if rsp.status() == http::StatusCode::UNAUTHORIZED {
+            if is_token_expired_response(&rsp, &token) {
+                self.emit(&TokenEvent::TokenExpired);
+            }
+
+            if let Some(token) = self.renew_token(Some(&token))? {
+                log::info!("access token was rejected, retrying with a renewed token");
+                return self.send(&request, &token).map_err(ApiError::client);
+            }
+        }
+
+        Ok(rsp)
+    }
+
+    /// Returns the access token to send a request with, renewing the stored one if needed.
+    ///
+    /// An expired token is refreshed when it carries a refresh token. Otherwise, flows that can
+    /// obtain a token without user interaction (such as Client Credentials) request a new one,
+    /// which also covers the case where no token was requested yet.
+    fn renew_token_if_needed(&self) -> Result<Token, ApiError<RestError>> {
+        let stored = self.stored_token();
+
+        match &stored {
+            Some(token) if !token.is_expired() => return Ok(token.clone()),
+            Some(_) => self.emit(&TokenEvent::TokenExpired),
+            None => {}
+        }
+
+        match self.renew_token(stored.as_ref())? {
+            Some(token) => Ok(token),
+            None => stored.ok_or_else(|| AuthError::EmptyAccessToken.into()),
+        }
+    }
+
+    /// Obtains a new access token to replace `stale`, either by refreshing it or by requesting
+    /// a new one.
+    ///
+    /// Renewals are serialized, so a token replaced while waiting is returned as is instead of
+    /// being renewed again.
+    ///
+    /// Returns `None` if no token could be obtained without user interaction.
+    fn renew_token(&self, stale: Option<&Token>) -> Result<Option<Token>, ApiError<RestError>> {
+        let lock = self.renewal_lock();
+        let _guard = lock.lock();
+
+        let current = self.stored_token();
+
+        if let Some(current) = &current {
+            let replaced = stale.is_none_or(|stale| stale.access_token != current.access_token);
+
+            if replaced && !current.is_expired() {
+                return Ok(Some(current.clone()));
+            }
+        }
+
+        let Some((result, renewal)) = self.request_renewed_token(current.as_ref()) else {
+            return Ok(None);
+        };
+
+        result
+            .map(|token| self.store_renewed_token(token, renewal))
+            .inspect_err(|e| self.emit(&TokenEvent::refresh_failed(e)))
+    }
+}
+
+/// An asynchronous client that renews its access token on its own.
+///
+/// This is the asynchronous counterpart of [`TokenRenewal`].
+#[async_trait]
+pub(crate) trait AsyncTokenRenewal: Sync {
+    /// Returns the lock serializing the renewals of the token.
+    fn renewal_lock(&self) -> Arc<futures_util::lock::Mutex<()>>;
+
+    /// Returns the stored access token, if any.
+    fn stored_token(&self) -> Option<Token>;
+
+    /// Requests a token to replace `current`, or returns `None` if there is no way of obtaining
+    /// one without user interaction.
+    async fn request_renewed_token(&self, current: Option<&Token>) -> Option<RenewedToken>;
+
+    /// Stores a renewed token and returns a copy of it, or returns `None` if the token must be
+    /// discarded, for example because the client was logged out in the meantime.
+    fn store_renewed_token(&self, token: Token, renewal: Renewal) -> Option<Token>;
+
+    /// Notifies the handlers of a token event.
+    fn emit(&self, event: &TokenEvent);
+
+    /// Sends a request without authorization.
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<HttpResponse<Bytes>, RestError>;
+
+    /// Sends a request authorized with the given access token.
+    async fn send(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        token: &Token,
+    ) -> Result<HttpResponse<Bytes>, RestError> {
+        let mut http_request = clone_request(request);
+        set_bearer_header(http_request.headers_mut(), token)?;
+        self.execute(http_request).await
+    }
+
+    /// Performs a REST query with the stored access token, renewing it first if it expired.
+    ///
+    /// If Spotify rejects the access token with a `401 Unauthorized` response, the token is
+    /// renewed once and the request is replayed.
+    async fn rest_async_auth(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<RestError>> {
+        let token = self.renew_token_if_needed().await?;
+
+        let request = request.body(body).map_err(|e| ApiError::client(e.into()))?;
+
+        let rsp = self
+            .send(&request, &token)
+            .await
+            .map_err(ApiError::client)?;
+
+        if rsp.status() == http::StatusCode::UNAUTHORIZED {
+            if is_token_expired_response(&rsp, &token) {
+                self.emit(&TokenEvent::TokenExpired);
+            }
+
+            if let Some(token) = self.renew_token(Some(&token)).await? {
+                log::info!("access token was rejected, retrying with a renewed token");
+                return self.send(&request, &token).await.map_err(ApiError::client);
+            }
+        }
+
+        Ok(rsp)
+    }
+
+    /// Returns the access token to send a request with, renewing the stored one if needed.
+    ///
+    /// See [`TokenRenewal::renew_token_if_needed`].
+    async fn renew_token_if_needed(&self) -> Result<Token, ApiError<RestError>> {
+        let stored = self.stored_token();
+
+        match &stored {
+            Some(token) if !token.is_expired() => return Ok(token.clone()),
+            Some(_) => self.emit(&TokenEvent::TokenExpired),
+            None => {}
+        }
+
+        match self.renew_token(stored.as_ref()).await? {
+            Some(token) => Ok(token),
+            None => stored.ok_or_else(|| AuthError::EmptyAccessToken.into()),
+        }
+    }
+
+    /// Obtains a new access token to replace `stale`.
+    ///
+    /// See [`TokenRenewal::renew_token`].
+    async fn renew_token(
+        &self,
+        stale: Option<&Token>,
+    ) -> Result<Option<Token>, ApiError<RestError>> {
+        let lock = self.renewal_lock();
+        let _guard = lock.lock().await;
+
+        let current = self.stored_token();
+
+        if let Some(current) = &current {
+            let replaced = stale.is_none_or(|stale| stale.access_token != current.access_token);
+
+            if replaced && !current.is_expired() {
+                return Ok(Some(current.clone()));
+            }
+        }
+
+        let Some((result, renewal)) = self.request_renewed_token(current.as_ref()).await else {
+            return Ok(None);
+        };
+
+        result
+            .map(|token| self.store_renewed_token(token, renewal))
+            .inspect_err(|e| self.emit(&TokenEvent::refresh_failed(e)))
+    }
+}
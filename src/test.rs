@@ -1,4 +1,5 @@
 pub mod client;
+pub mod server;
 
 /// Asserts that the given JSON string can be deserialized into the given type.
 macro_rules! assert_deserialized {
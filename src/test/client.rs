@@ -1,5 +1,5 @@
 use crate::{
-    api::{ApiError, AsyncClient, Client, RestClient},
+    api::{ApiError, AsyncClient, Client, RestClient, UrlBase},
     model::Page,
 };
 use async_trait::async_trait;
@@ -15,6 +15,9 @@ pub struct ExpectedUrl {
     // #[builder(default = "Method::GET")]
     pub method: Method,
 
+    // #[builder(default = "UrlBase::ApiV1")]
+    pub url_base: UrlBase,
+
     pub endpoint: &'static str,
 
     // #[builder(default)]
@@ -46,9 +49,9 @@ impl ExpectedUrl {
         assert_eq!(url.scheme(), "https");
         assert_eq!(url.username(), "");
         assert_eq!(url.password(), None);
-        assert_eq!(url.host_str().unwrap(), "api.spotify.com");
+        assert_eq!(url.host_str().unwrap(), self.host());
         assert_eq!(url.port(), None);
-        assert_eq!(url.path(), format!("/v1/{}", self.endpoint));
+        assert_eq!(url.path(), self.path());
 
         let mut count = 0;
 
@@ -68,6 +71,20 @@ impl ExpectedUrl {
         assert_eq!(url.fragment(), None);
     }
 
+    fn host(&self) -> &'static str {
+        match self.url_base {
+            UrlBase::ApiV1 => "api.spotify.com",
+            UrlBase::Accounts => "accounts.spotify.com",
+        }
+    }
+
+    fn path(&self) -> String {
+        match self.url_base {
+            UrlBase::ApiV1 => format!("/v1/{}", self.endpoint),
+            UrlBase::Accounts => format!("/{}", self.endpoint),
+        }
+    }
+
     #[inline(always)]
     fn is_pagination_key(key: &str) -> bool {
         matches!(key, "limit" | "offset")
@@ -76,6 +93,7 @@ impl ExpectedUrl {
 
 pub struct ExpectedUrlBuilder {
     method: Method,
+    url_base: UrlBase,
     endpoint: &'static str,
     query: Option<Vec<(Cow<'static, str>, Cow<'static, str>)>>,
     content_type: Option<String>,
@@ -90,6 +108,11 @@ impl ExpectedUrlBuilder {
         self
     }
 
+    pub fn url_base(&mut self, url_base: UrlBase) -> &mut Self {
+        self.url_base = url_base;
+        self
+    }
+
     pub fn endpoint(&mut self, endpoint: &'static str) -> &mut Self {
         self.endpoint = endpoint;
         self
@@ -125,6 +148,7 @@ impl ExpectedUrlBuilder {
     pub fn build(&self) -> ExpectedUrl {
         ExpectedUrl {
             method: self.method.clone(),
+            url_base: self.url_base,
             endpoint: self.endpoint,
             query: self.query.clone().unwrap_or_default(),
             content_type: self.content_type.clone(),
@@ -139,6 +163,7 @@ impl Default for ExpectedUrlBuilder {
     fn default() -> Self {
         Self {
             method: Method::GET,
+            url_base: UrlBase::ApiV1,
             endpoint: "",
             query: None,
             content_type: None,
@@ -181,10 +206,7 @@ impl SingleTestClient {
     {
         let mut client = MockClient::default();
 
-        let request = (
-            expected.method.clone(),
-            format!("/v1/{}", expected.endpoint),
-        );
+        let request = (expected.method.clone(), expected.path());
         let response = MockResponse {
             status: expected.status,
             data: data.into(),
@@ -0,0 +1,71 @@
+use parking_lot::Mutex;
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+use url::Url;
+
+/// A token response from the Accounts service, valid for an hour.
+pub const TOKEN: &str = r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#;
+
+/// Starts a server answering each request with the status and body `respond` returns for
+/// its request line, such as `POST /api/token`.
+///
+/// Returns the base URL of the server and the request lines it received.
+pub fn serve(
+    respond: impl Fn(&str) -> (u16, &'static str) + Send + 'static,
+) -> (Url, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = requests.clone();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            reader.read_exact(&mut vec![0; content_length]).unwrap();
+
+            // Leave time for concurrent requests to pile up.
+            thread::sleep(Duration::from_millis(20));
+
+            let request_line = request_line
+                .trim_end()
+                .trim_end_matches(" HTTP/1.1")
+                .trim_end_matches('?')
+                .to_owned();
+            let (status, body) = respond(&request_line);
+            received.lock().push(request_line);
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (url, requests)
+}